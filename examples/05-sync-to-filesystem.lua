@@ -0,0 +1,13 @@
+-- Instead of saving each model as its own file, Remodel can write out a whole
+-- part of a place the same way a Rojo project would lay it out. Scripts become
+-- Lua files, folders become directories, and everything else is saved as a
+-- model file. This is a good way to move an existing place into Rojo!
+local game = remodel.readPlaceFile("test-models/place-with-models.rbxlx")
+
+-- Each service gets its own directory, like src/ReplicatedStorage.
+for _, serviceName in ipairs({ "ReplicatedStorage", "Workspace" }) do
+	remodel.writeDirTree(game[serviceName], "temp/src/" .. serviceName)
+end
+
+-- These directories can now be used as $path in a default.project.json, and
+-- remodel.readProject or rojo build will turn them back into instances.
@@ -0,0 +1,637 @@
+//! Compares two instance trees. Instances are matched up by their name and
+//! class under matching parents, and instances that disappeared from one place
+//! and showed up in another are treated as moved.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use rbx_dom_weak::{
+    types::{Ref, Variant},
+    Instance, WeakDom,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+use crate::{
+    dom_util::{descendants, instance_path},
+    reflection::find_default_property,
+    variant_json::variant_to_json,
+};
+
+/// How many unchanged lines to show around changed lines of a script.
+const SOURCE_DIFF_CONTEXT: usize = 3;
+
+/// Above this many lines squared, a changed part of a script is shown as
+/// entirely removed and added instead of finding the smallest diff.
+const MAX_SOURCE_DIFF_CELLS: usize = 4_000_000;
+
+pub struct TreeDiff<'a> {
+    old_dom: &'a WeakDom,
+    old_root: Ref,
+    new_dom: &'a WeakDom,
+    new_root: Ref,
+    old_to_new: HashMap<Ref, Ref>,
+    new_to_old: HashMap<Ref, Ref>,
+
+    /// Instances in the new tree that don't exist in the old tree. Only the
+    /// top of each new subtree is listed.
+    pub added: Vec<Ref>,
+
+    /// Instances in the old tree that don't exist in the new tree. Only the
+    /// top of each removed subtree is listed.
+    pub removed: Vec<Ref>,
+
+    /// Instances that have a different parent in the new tree, as pairs of
+    /// the old and new instance.
+    pub moved: Vec<(Ref, Ref)>,
+
+    /// Instances that exist in both trees but have different properties.
+    pub changed: Vec<ChangedInstance<'a>>,
+}
+
+pub struct ChangedInstance<'a> {
+    pub new: Ref,
+    pub properties: Vec<PropertyChange<'a>>,
+}
+
+pub struct PropertyChange<'a> {
+    pub name: String,
+    pub old: Option<&'a Variant>,
+    pub new: Option<&'a Variant>,
+}
+
+impl<'a> PropertyChange<'a> {
+    /// Gives a line diff of the property if it's the source of a script.
+    pub fn source_diff(&self) -> Option<String> {
+        match (self.name.as_str(), self.old, self.new) {
+            ("Source", Some(Variant::String(old)), Some(Variant::String(new))) => {
+                Some(line_diff(old, new))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> TreeDiff<'a> {
+    /// Compares the instance `old_root` in `old_dom` with the instance
+    /// `new_root` in `new_dom`. The roots are always matched with each other,
+    /// even if their names or classes differ.
+    pub fn new(old_dom: &'a WeakDom, old_root: Ref, new_dom: &'a WeakDom, new_root: Ref) -> Self {
+        let mut diff = TreeDiff {
+            old_dom,
+            old_root,
+            new_dom,
+            new_root,
+            old_to_new: HashMap::new(),
+            new_to_old: HashMap::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            moved: Vec::new(),
+            changed: Vec::new(),
+        };
+
+        diff.match_subtree(old_root, new_root);
+        diff.match_moved();
+        diff.collect_changes();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.moved.is_empty()
+            && self.changed.is_empty()
+    }
+
+    pub fn old_instance(&self, id: Ref) -> &'a Instance {
+        self.old_dom.get_by_ref(id).unwrap()
+    }
+
+    pub fn new_instance(&self, id: Ref) -> &'a Instance {
+        self.new_dom.get_by_ref(id).unwrap()
+    }
+
+    pub fn old_path(&self, id: Ref) -> String {
+        instance_path(self.old_dom, self.old_root, id)
+    }
+
+    pub fn new_path(&self, id: Ref) -> String {
+        instance_path(self.new_dom, self.new_root, id)
+    }
+
+    /// Finds the instance in the new tree that was matched up with the given
+    /// instance from the old tree.
+    pub fn matched_new(&self, old_id: Ref) -> Option<Ref> {
+        self.old_to_new.get(&old_id).copied()
+    }
+
+    /// Finds the instance in the old tree that was matched up with the given
+    /// instance from the new tree.
+    pub fn matched_old(&self, new_id: Ref) -> Option<Ref> {
+        self.new_to_old.get(&new_id).copied()
+    }
+
+    /// Describes the diff for people, with one line per instance followed by
+    /// its changed properties.
+    pub fn to_text(&self) -> String {
+        let mut lines = Vec::new();
+
+        for &id in &self.added {
+            let instance = self.new_instance(id);
+            lines.push(format!("+ {} ({})", self.new_path(id), instance.class));
+        }
+
+        for &id in &self.removed {
+            let instance = self.old_instance(id);
+            lines.push(format!("- {} ({})", self.old_path(id), instance.class));
+        }
+
+        for &(old, new) in &self.moved {
+            lines.push(format!(
+                "> {} -> {} ({})",
+                self.old_path(old),
+                self.new_path(new),
+                self.new_instance(new).class
+            ));
+        }
+
+        for changed in &self.changed {
+            lines.push(format!(
+                "~ {} ({})",
+                self.new_path(changed.new),
+                self.new_instance(changed.new).class
+            ));
+
+            for property in &changed.properties {
+                match property.source_diff() {
+                    Some(diff) => {
+                        lines.push(format!("    {}:", property.name));
+                        lines.extend(diff.lines().map(|line| format!("      {}", line)));
+                    }
+                    None => lines.push(format!(
+                        "    {}: {} -> {}",
+                        property.name,
+                        self.display_value(self.old_dom, self.old_root, property.old),
+                        self.display_value(self.new_dom, self.new_root, property.new),
+                    )),
+                }
+            }
+        }
+
+        if self.is_empty() {
+            lines.push("No differences".to_owned());
+        } else {
+            lines.push(format!(
+                "{} added, {} removed, {} moved, {} changed",
+                self.added.len(),
+                self.removed.len(),
+                self.moved.len(),
+                self.changed.len()
+            ));
+        }
+
+        let mut text = lines.join("\n");
+        text.push('\n');
+        text
+    }
+
+    /// Describes the diff for other tools. Values are written the same way as
+    /// in Rojo projects along with their type, and scripts that changed have a
+    /// line diff of their source instead.
+    pub fn to_json(&self) -> JsonValue {
+        let added: Vec<_> = self
+            .added
+            .iter()
+            .map(|&id| {
+                json!({
+                    "path": self.new_path(id),
+                    "className": self.new_instance(id).class,
+                })
+            })
+            .collect();
+
+        let removed: Vec<_> = self
+            .removed
+            .iter()
+            .map(|&id| {
+                json!({
+                    "path": self.old_path(id),
+                    "className": self.old_instance(id).class,
+                })
+            })
+            .collect();
+
+        let moved: Vec<_> = self
+            .moved
+            .iter()
+            .map(|&(old, new)| {
+                json!({
+                    "from": self.old_path(old),
+                    "to": self.new_path(new),
+                    "className": self.new_instance(new).class,
+                })
+            })
+            .collect();
+
+        let changed: Vec<_> = self
+            .changed
+            .iter()
+            .map(|changed| {
+                let mut properties = JsonMap::new();
+
+                for property in &changed.properties {
+                    let entry = match property.source_diff() {
+                        Some(diff) => json!({ "diff": diff }),
+                        None => json!({
+                            "old": self.json_value(self.old_dom, self.old_root, property.old),
+                            "new": self.json_value(self.new_dom, self.new_root, property.new),
+                        }),
+                    };
+
+                    properties.insert(property.name.clone(), entry);
+                }
+
+                json!({
+                    "path": self.new_path(changed.new),
+                    "className": self.new_instance(changed.new).class,
+                    "properties": properties,
+                })
+            })
+            .collect();
+
+        json!({
+            "added": added,
+            "removed": removed,
+            "moved": moved,
+            "changed": changed,
+        })
+    }
+
+    fn display_value(&self, dom: &WeakDom, root: Ref, value: Option<&Variant>) -> String {
+        match value {
+            None => "nil".to_owned(),
+            Some(Variant::Ref(referent)) if referent.is_none() => "nil".to_owned(),
+            Some(Variant::Ref(referent)) => instance_path(dom, root, *referent),
+            Some(value) => match variant_to_json(value) {
+                Some(json) => json.to_string(),
+                None => format!("<{:?}>", value.ty()),
+            },
+        }
+    }
+
+    fn json_value(&self, dom: &WeakDom, root: Ref, value: Option<&Variant>) -> JsonValue {
+        let value = match value {
+            Some(value) => value,
+            None => return JsonValue::Null,
+        };
+
+        let json = match value {
+            Variant::Ref(referent) if referent.is_none() => JsonValue::Null,
+            Variant::Ref(referent) => JsonValue::String(instance_path(dom, root, *referent)),
+            _ => variant_to_json(value).unwrap_or(JsonValue::Null),
+        };
+
+        json!({
+            "type": format!("{:?}", value.ty()),
+            "value": json,
+        })
+    }
+
+    /// Matches up `old_id` and `new_id`, then their children that have the
+    /// same name and class. Children with the same name and class are matched
+    /// in order.
+    fn match_subtree(&mut self, old_id: Ref, new_id: Ref) {
+        let old_dom = self.old_dom;
+        let new_dom = self.new_dom;
+
+        self.old_to_new.insert(old_id, new_id);
+        self.new_to_old.insert(new_id, old_id);
+
+        let mut candidates: HashMap<(&str, &str), VecDeque<Ref>> = HashMap::new();
+        for &child_id in old_dom.get_by_ref(old_id).unwrap().children() {
+            if !self.old_to_new.contains_key(&child_id) {
+                let child = old_dom.get_by_ref(child_id).unwrap();
+                candidates
+                    .entry((child.name.as_str(), child.class.as_str()))
+                    .or_default()
+                    .push_back(child_id);
+            }
+        }
+
+        for &child_id in new_dom.get_by_ref(new_id).unwrap().children() {
+            if self.new_to_old.contains_key(&child_id) {
+                continue;
+            }
+
+            let child = new_dom.get_by_ref(child_id).unwrap();
+            let old_child = candidates
+                .get_mut(&(child.name.as_str(), child.class.as_str()))
+                .and_then(VecDeque::pop_front);
+
+            if let Some(old_child) = old_child {
+                self.match_subtree(old_child, child_id);
+            }
+        }
+    }
+
+    /// Matches instances that are left over after matching by parent, which
+    /// happens when an instance moved to a different parent.
+    fn match_moved(&mut self) {
+        let old_dom = self.old_dom;
+        let new_dom = self.new_dom;
+
+        let mut candidates: HashMap<(&str, &str), VecDeque<Ref>> = HashMap::new();
+        for id in descendants(old_dom, self.old_root) {
+            if !self.old_to_new.contains_key(&id) {
+                let instance = old_dom.get_by_ref(id).unwrap();
+                candidates
+                    .entry((instance.name.as_str(), instance.class.as_str()))
+                    .or_default()
+                    .push_back(id);
+            }
+        }
+
+        for id in descendants(new_dom, self.new_root) {
+            if self.new_to_old.contains_key(&id) {
+                continue;
+            }
+
+            let instance = new_dom.get_by_ref(id).unwrap();
+            if let Some(queue) =
+                candidates.get_mut(&(instance.name.as_str(), instance.class.as_str()))
+            {
+                // Matching a moved instance also matches its descendants, so
+                // some candidates may have been matched since.
+                while let Some(old_id) = queue.pop_front() {
+                    if !self.old_to_new.contains_key(&old_id) {
+                        self.match_subtree(old_id, id);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_changes(&mut self) {
+        for new_id in descendants(self.new_dom, self.new_root) {
+            let new_instance = self.new_instance(new_id);
+
+            let old_id = match self.new_to_old.get(&new_id) {
+                Some(&old_id) => old_id,
+                None => {
+                    if self.new_to_old.contains_key(&new_instance.parent()) {
+                        self.added.push(new_id);
+                    }
+                    continue;
+                }
+            };
+
+            let old_instance = self.old_instance(old_id);
+
+            if new_id != self.new_root
+                && self.old_to_new.get(&old_instance.parent()) != Some(&new_instance.parent())
+            {
+                self.moved.push((old_id, new_id));
+            }
+
+            let properties = self.compare_properties(old_instance, new_instance);
+            if !properties.is_empty() {
+                self.changed.push(ChangedInstance {
+                    new: new_id,
+                    properties,
+                });
+            }
+        }
+
+        for old_id in descendants(self.old_dom, self.old_root) {
+            let old_instance = self.old_instance(old_id);
+
+            if !self.old_to_new.contains_key(&old_id)
+                && self.old_to_new.contains_key(&old_instance.parent())
+            {
+                self.removed.push(old_id);
+            }
+        }
+    }
+
+    /// Properties that aren't set are treated as having their default value,
+    /// so that an instance created with `Instance.new` and one from a file
+    /// can be compared.
+    fn compare_properties(
+        &self,
+        old_instance: &'a Instance,
+        new_instance: &'a Instance,
+    ) -> Vec<PropertyChange<'a>> {
+        let names: BTreeSet<&String> = old_instance
+            .properties
+            .keys()
+            .chain(new_instance.properties.keys())
+            .collect();
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let old = old_instance
+                    .properties
+                    .get(name)
+                    .or_else(|| find_default_property(&old_instance.class, name));
+                let new = new_instance
+                    .properties
+                    .get(name)
+                    .or_else(|| find_default_property(&new_instance.class, name));
+
+                if self.values_equal(old, new) {
+                    None
+                } else {
+                    Some(PropertyChange {
+                        name: name.clone(),
+                        old,
+                        new,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// References are equal if they point to instances that were matched up
+    /// with each other.
+    fn values_equal(&self, old: Option<&Variant>, new: Option<&Variant>) -> bool {
+        match (old, new) {
+            (Some(Variant::Ref(old)), Some(Variant::Ref(new))) => {
+                self.old_to_new.get(old).unwrap_or(old) == new
+            }
+            (Some(old), Some(new)) if is_empty_value(old) && is_empty_value(new) => true,
+            (Some(value), None) | (None, Some(value)) => is_empty_value(value),
+            (old, new) => old == new,
+        }
+    }
+}
+
+/// Empty attributes and tags can be stored in a few different ways depending
+/// on where an instance came from, so they're all treated as the same.
+pub fn is_empty_value(value: &Variant) -> bool {
+    match value {
+        Variant::Ref(referent) => referent.is_none(),
+        Variant::BinaryString(value) => AsRef::<[u8]>::as_ref(value).is_empty(),
+        Variant::Attributes(attributes) => attributes.iter().next().is_none(),
+        Variant::Tags(tags) => tags.iter().next().is_none(),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineChange {
+    Same,
+    Removed,
+    Added,
+}
+
+/// Compares two pieces of text line by line and describes the differences
+/// as a unified diff, like `diff -u` does.
+pub fn line_diff(old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let changes = diff_lines(&old_lines, &new_lines);
+
+    let mut output = Vec::new();
+    let mut index = 0;
+
+    while index < changes.len() {
+        if changes[index].0 == LineChange::Same {
+            index += 1;
+            continue;
+        }
+
+        // Grow the hunk until there are enough unchanged lines in a row to
+        // separate it from the next one.
+        let start = index.saturating_sub(SOURCE_DIFF_CONTEXT);
+        let mut end = index;
+        let mut unchanged = 0;
+
+        while end < changes.len() && unchanged <= SOURCE_DIFF_CONTEXT * 2 {
+            if changes[end].0 == LineChange::Same {
+                unchanged += 1;
+            } else {
+                unchanged = 0;
+            }
+            end += 1;
+        }
+
+        let end = end - unchanged.saturating_sub(SOURCE_DIFF_CONTEXT);
+        let hunk = &changes[start..end];
+
+        let old_before = changes[..start]
+            .iter()
+            .filter(|(change, _)| *change != LineChange::Added)
+            .count();
+        let new_before = changes[..start]
+            .iter()
+            .filter(|(change, _)| *change != LineChange::Removed)
+            .count();
+        let old_count = hunk
+            .iter()
+            .filter(|(change, _)| *change != LineChange::Added)
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|(change, _)| *change != LineChange::Removed)
+            .count();
+
+        output.push(format!(
+            "@@ -{},{} +{},{} @@",
+            old_before + (old_count > 0) as usize,
+            old_count,
+            new_before + (new_count > 0) as usize,
+            new_count
+        ));
+
+        for (change, line) in hunk {
+            let prefix = match change {
+                LineChange::Same => ' ',
+                LineChange::Removed => '-',
+                LineChange::Added => '+',
+            };
+            output.push(format!("{}{}", prefix, line));
+        }
+
+        index = end;
+    }
+
+    output.join("\n")
+}
+
+/// Finds the longest common subsequence of lines to tell which lines were
+/// removed and added.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(LineChange, &'a str)> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut changes: Vec<_> = old[..prefix]
+        .iter()
+        .map(|line| (LineChange::Same, *line))
+        .collect();
+
+    let width = new_middle.len() + 1;
+    if (old_middle.len() + 1) * width > MAX_SOURCE_DIFF_CELLS {
+        changes.extend(old_middle.iter().map(|line| (LineChange::Removed, *line)));
+        changes.extend(new_middle.iter().map(|line| (LineChange::Added, *line)));
+    } else {
+        // lengths[i * width + j] is the length of the longest common
+        // subsequence of old_middle[i..] and new_middle[j..].
+        let mut lengths = vec![0u32; (old_middle.len() + 1) * width];
+
+        for i in (0..old_middle.len()).rev() {
+            for j in (0..new_middle.len()).rev() {
+                lengths[i * width + j] = if old_middle[i] == new_middle[j] {
+                    lengths[(i + 1) * width + j + 1] + 1
+                } else {
+                    lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < old_middle.len() && j < new_middle.len() {
+            if old_middle[i] == new_middle[j] {
+                changes.push((LineChange::Same, old_middle[i]));
+                i += 1;
+                j += 1;
+            } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+                changes.push((LineChange::Removed, old_middle[i]));
+                i += 1;
+            } else {
+                changes.push((LineChange::Added, new_middle[j]));
+                j += 1;
+            }
+        }
+
+        changes.extend(
+            old_middle[i..]
+                .iter()
+                .map(|line| (LineChange::Removed, *line)),
+        );
+        changes.extend(
+            new_middle[j..]
+                .iter()
+                .map(|line| (LineChange::Added, *line)),
+        );
+    }
+
+    changes.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| (LineChange::Same, *line)),
+    );
+
+    changes
+}
@@ -0,0 +1,43 @@
+//! Helpers for walking instance trees, shared by the commands that describe
+//! or compare them.
+
+use rbx_dom_weak::{types::Ref, WeakDom};
+
+/// Lists the given instance and all of its descendants, parents first.
+pub fn descendants(dom: &WeakDom, root: Ref) -> Vec<Ref> {
+    let mut ids = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(id) = stack.pop() {
+        ids.push(id);
+
+        if let Some(instance) = dom.get_by_ref(id) {
+            stack.extend(instance.children().iter().rev());
+        }
+    }
+
+    ids
+}
+
+/// Finds the path of the instance like `Instance:GetFullName`, starting from
+/// `root`. Like in Roblox, the name of a DataModel is left out.
+pub fn instance_path(dom: &WeakDom, root: Ref, id: Ref) -> String {
+    let mut names = Vec::new();
+    let mut current = dom.get_by_ref(id);
+
+    while let Some(instance) = current {
+        let is_root = instance.referent() == root || instance.parent().is_none();
+        if !is_root || instance.class != "DataModel" || names.is_empty() {
+            names.push(instance.name.as_str());
+        }
+
+        if is_root {
+            break;
+        }
+
+        current = dom.get_by_ref(instance.parent());
+    }
+
+    names.reverse();
+    names.join(".")
+}
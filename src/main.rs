@@ -1,8 +1,16 @@
+mod brick_color;
+mod diff;
+mod dom_util;
+mod merge;
+mod reflection;
 mod remodel_api;
 mod remodel_context;
+mod repl;
 mod roblox_api;
 mod sniff_type;
+mod tree;
 mod value;
+mod variant_json;
 
 use std::{
     env, fs,
@@ -16,7 +24,15 @@ use backtrace::Backtrace;
 use mlua::{Lua, MultiValue, ToLua};
 use structopt::StructOpt;
 
-use crate::{remodel_api::RemodelApi, remodel_context::RemodelContext, roblox_api::RobloxApi};
+use crate::{
+    diff::TreeDiff,
+    remodel_api::{Remodel, RemodelApi},
+    remodel_context::RemodelContext,
+    repl::run_repl,
+    roblox_api::RobloxApi,
+    sniff_type::DocumentType,
+    tree::TreeView,
+};
 
 #[derive(Debug, StructOpt)]
 #[structopt(
@@ -66,6 +82,83 @@ enum Subcommand {
         /// Arguments to pass to the script as a list of strings.
         args: Vec<String>,
     },
+
+    /// Start an interactive Lua prompt with the same APIs that scripts have.
+    ///
+    /// Values returned by each chunk are printed. Chunks that aren't complete
+    /// yet, like an unfinished function, continue on the next line.
+    Repl,
+
+    /// Convert a place or model file to another format.
+    ///
+    /// The format is chosen by the extension of the output file. Places can
+    /// only be converted to places, and models to models.
+    ///
+    /// If the input is a directory, every place and model file inside of it
+    /// is converted to the format given by --format, keeping the same layout
+    /// in the output directory.
+    Convert {
+        /// Path to the place or model to convert, or a directory of them.
+        input: PathBuf,
+
+        /// Path to write the converted file to, or the directory to write
+        /// converted files to.
+        output: PathBuf,
+
+        /// The format to convert a directory of files to, either `xml` or
+        /// `binary`.
+        #[structopt(long, parse(try_from_str = parse_format))]
+        format: Option<DocumentType>,
+    },
+
+    /// Show the instances inside of a place or model file.
+    ///
+    /// Each instance is shown with its name and class, indented under its
+    /// parent.
+    Tree {
+        /// Path to the place or model to show.
+        file: PathBuf,
+
+        /// Only show instances up to this many levels below the top.
+        #[structopt(long)]
+        depth: Option<usize>,
+
+        /// Only show instances of this class or one of its subclasses, along
+        /// with their ancestors.
+        #[structopt(long)]
+        class: Option<String>,
+
+        /// Start at the instance with this path, like `Workspace.Map`, instead
+        /// of at the top of the file.
+        #[structopt(long)]
+        path: Option<String>,
+
+        /// Show the value of this property on each instance that has it. Can
+        /// be specified more than once.
+        #[structopt(long("property"), short("p"), number_of_values(1))]
+        properties: Vec<String>,
+
+        /// Print the tree as JSON instead of text.
+        #[structopt(long)]
+        json: bool,
+    },
+
+    /// Show the differences between two place or model files.
+    ///
+    /// Instances are matched up by their name and class. Added, removed,
+    /// moved, and changed instances are listed, along with a line diff of any
+    /// scripts that changed.
+    Diff {
+        /// Path to the old version of the place or model.
+        old: PathBuf,
+
+        /// Path to the new version of the place or model.
+        new: PathBuf,
+
+        /// Print the differences as JSON instead of text.
+        #[structopt(long)]
+        json: bool,
+    },
 }
 
 fn main() {
@@ -99,24 +192,110 @@ fn run(options: Options) -> Result<(), anyhow::Error> {
             RobloxApi::inject(&lua)?;
 
             let chunk = lua.load(&contents).set_name(&chunk_name)?;
-            chunk.call(MultiValue::from_vec(lua_args))?;
+            chunk.call::<_, ()>(MultiValue::from_vec(lua_args))?;
+
+            Ok(())
+        }
+        Subcommand::Repl => {
+            let lua = Lua::new();
+
+            RemodelContext::new(auth_cookie, api_key).inject(&lua)?;
+
+            RemodelApi::inject(&lua)?;
+            RobloxApi::inject(&lua)?;
+
+            run_repl(&lua)
+        }
+        Subcommand::Convert {
+            input,
+            output,
+            format,
+        } => {
+            let lua = Lua::new();
+            RemodelContext::new(auth_cookie, api_key).inject(&lua)?;
+
+            if input.is_dir() {
+                let format = format.ok_or_else(|| {
+                    anyhow::anyhow!("--format is required when converting a directory")
+                })?;
+
+                Remodel::convert_dir(&lua, &input, &output, format)?;
+            } else {
+                if format.is_some() {
+                    anyhow::bail!(
+                        "--format can only be used when converting a directory, \
+                         since the format of a file is chosen by its extension"
+                    );
+                }
+
+                Remodel::convert_file(&lua, &input, &output)?;
+            }
+
+            Ok(())
+        }
+        Subcommand::Tree {
+            file,
+            depth,
+            class,
+            path,
+            properties,
+            json,
+        } => {
+            let tree = Remodel::read_file_tree(&file)?;
+            let view = TreeView::new(&tree, path.as_deref(), depth, class.as_deref(), properties)?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&view.to_json())?);
+            } else {
+                print!("{}", view.to_text());
+            }
+
+            Ok(())
+        }
+        Subcommand::Diff { old, new, json } => {
+            let old_tree = Remodel::read_file_tree(&old)?;
+            let new_tree = Remodel::read_file_tree(&new)?;
+            let diff = TreeDiff::new(
+                &old_tree,
+                old_tree.root_ref(),
+                &new_tree,
+                new_tree.root_ref(),
+            );
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&diff.to_json())?);
+            } else {
+                print!("{}", diff.to_text());
+            }
 
             Ok(())
         }
     }
 }
 
+fn parse_format(format: &str) -> Result<DocumentType, String> {
+    match format {
+        "xml" => Ok(DocumentType::Xml),
+        "binary" => Ok(DocumentType::Binary),
+        _ => Err(format!(
+            "invalid format '{}', expected 'xml' or 'binary'",
+            format
+        )),
+    }
+}
+
 /// Load the script from the given CLI-supplied path.
 ///
 /// Returns the contents of the script followed by its chunk name that should be
-/// given to Lua.
+/// given to Lua. Scripts from files are named after their path, which lets
+/// `require` find modules next to them.
 fn load_script(script: &str) -> io::Result<(String, String)> {
     // Passing `-` indicates that the script should be read from stdin.
     if script == "-" {
         let mut contents = String::new();
         io::stdin().read_to_string(&mut contents)?;
 
-        return Ok((contents, "stdin".to_owned()));
+        return Ok((contents, "=stdin".to_owned()));
     }
 
     log::trace!("Reading script from {}", script);
@@ -126,15 +305,7 @@ fn load_script(script: &str) -> io::Result<(String, String)> {
     match fs::read_to_string(file_path) {
         // If the input is an exact file name that exists, we'll run that
         // script.
-        Ok(contents) => {
-            let file_name = file_path
-                .file_name()
-                .unwrap()
-                .to_string_lossy()
-                .into_owned();
-
-            Ok((contents, file_name))
-        }
+        Ok(contents) => Ok((contents, format!("@{}", file_path.display()))),
 
         Err(full_path_err) => {
             // If the given script was not a file that exists, or if it was a directory,
@@ -152,8 +323,8 @@ fn load_script(script: &str) -> io::Result<(String, String)> {
 
                 log::trace!("Reading script from {}", remodel_path.display());
 
-                match fs::read_to_string(remodel_path) {
-                    Ok(contents) => Ok((contents, script.to_owned())),
+                match fs::read_to_string(&remodel_path) {
+                    Ok(contents) => Ok((contents, format!("@{}", remodel_path.display()))),
                     Err(remodel_err) => {
                         if remodel_err.kind() == io::ErrorKind::NotFound {
                             Err(full_path_err)
@@ -0,0 +1,703 @@
+//! Three-way merges of instance trees, for combining two copies of a place
+//! that were edited separately after starting from the same version.
+//!
+//! Instances are matched up the same way as in `diff`. When both sides made
+//! changes that can't be combined, our side wins and a conflict is recorded
+//! so that it can be resolved afterwards.
+
+use std::collections::{HashMap, HashSet};
+
+use rbx_dom_weak::{
+    types::{Ref, Variant},
+    Instance, InstanceBuilder, WeakDom,
+};
+
+use crate::{
+    diff::{is_empty_value, TreeDiff},
+    dom_util::descendants,
+    reflection::find_default_property,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Ours,
+    Theirs,
+}
+
+#[derive(Debug)]
+pub enum ConflictKind {
+    /// Both sides changed a property to different values. The merged instance
+    /// has our value.
+    Property(Box<PropertyConflict>),
+
+    /// One side deleted an instance that the other side changed. Instances
+    /// that we deleted stay deleted, and instances that they deleted are kept.
+    Deleted(Side),
+
+    /// Both sides moved an instance to different parents. The merged instance
+    /// is where we moved it.
+    Moved,
+}
+
+#[derive(Debug)]
+pub struct PropertyConflict {
+    pub name: String,
+    pub base: Option<Variant>,
+    pub ours: Option<Variant>,
+    pub theirs: Option<Variant>,
+}
+
+#[derive(Debug)]
+pub struct Conflict {
+    pub kind: ConflictKind,
+    pub base: Option<Ref>,
+    pub ours: Option<Ref>,
+    pub theirs: Option<Ref>,
+
+    /// The instance in the merged tree, unless it was deleted.
+    pub merged: Option<Ref>,
+
+    /// The instance in the merged tree that their side has as the parent of
+    /// this instance, which is where the instance would be if their side won.
+    pub theirs_parent: Option<Ref>,
+}
+
+pub struct Merge {
+    pub root: Ref,
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Merges the changes made from `base` to `ours` and from `base` to
+/// `theirs`, and puts the result into `dom` under `parent`.
+pub fn merge(dom: &mut WeakDom, base: Ref, ours: Ref, theirs: Ref, parent: Ref) -> Merge {
+    let (builder, conflicts) = {
+        let mut merger = Merger::new(dom, base, ours, theirs);
+        merger.decide_existence();
+        merger.decide_parents();
+        let builder = merger.build();
+
+        let conflicts = std::mem::take(&mut merger.conflicts)
+            .into_iter()
+            .map(|(node, kind)| merger.finish_conflict(node, kind))
+            .collect::<Vec<_>>();
+
+        (builder, conflicts)
+    };
+
+    let root = dom.insert(parent, builder);
+    Merge { root, conflicts }
+}
+
+/// One instance as it exists in each of the trees being merged.
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    base: Option<Ref>,
+    ours: Option<Ref>,
+    theirs: Option<Ref>,
+    exists: bool,
+    parent: Option<usize>,
+    merged: Option<Ref>,
+}
+
+/// A property value that can be compared between the three trees, where
+/// references are compared by which instance they point to.
+#[derive(PartialEq)]
+enum ValueKey<'a> {
+    Empty,
+    Value(&'a Variant),
+    Node(usize),
+    Outside(Ref),
+}
+
+struct Merger<'a> {
+    dom: &'a WeakDom,
+    nodes: Vec<Node>,
+    base_nodes: HashMap<Ref, usize>,
+    ours_nodes: HashMap<Ref, usize>,
+    theirs_nodes: HashMap<Ref, usize>,
+    ours_order: Vec<Ref>,
+    theirs_order: Vec<Ref>,
+    ours_modified: HashSet<Ref>,
+    theirs_modified: HashSet<Ref>,
+    conflicts: Vec<(usize, ConflictKind)>,
+}
+
+impl<'a> Merger<'a> {
+    fn new(dom: &'a WeakDom, base_root: Ref, ours_root: Ref, theirs_root: Ref) -> Self {
+        let ours_diff = TreeDiff::new(dom, base_root, dom, ours_root);
+        let theirs_diff = TreeDiff::new(dom, base_root, dom, theirs_root);
+
+        let mut merger = Merger {
+            dom,
+            nodes: Vec::new(),
+            base_nodes: HashMap::new(),
+            ours_nodes: HashMap::new(),
+            theirs_nodes: HashMap::new(),
+            ours_order: descendants(dom, ours_root),
+            theirs_order: descendants(dom, theirs_root),
+            ours_modified: modified_instances(dom, &ours_diff),
+            theirs_modified: modified_instances(dom, &theirs_diff),
+            conflicts: Vec::new(),
+        };
+
+        for base in descendants(dom, base_root) {
+            merger.add_node(Node {
+                base: Some(base),
+                ours: ours_diff.matched_new(base),
+                theirs: theirs_diff.matched_new(base),
+                ..Node::default()
+            });
+        }
+
+        for index in 0..merger.ours_order.len() {
+            let ours = merger.ours_order[index];
+            if !merger.ours_nodes.contains_key(&ours) {
+                merger.add_node(Node {
+                    ours: Some(ours),
+                    ..Node::default()
+                });
+            }
+        }
+
+        // An instance that both sides added with the same name and class in
+        // the same place is treated as the same instance.
+        for index in 0..merger.theirs_order.len() {
+            let theirs = merger.theirs_order[index];
+            if merger.theirs_nodes.contains_key(&theirs) {
+                continue;
+            }
+
+            match merger.find_added_by_both(theirs) {
+                Some(node) => {
+                    merger.nodes[node].theirs = Some(theirs);
+                    merger.theirs_nodes.insert(theirs, node);
+                }
+                None => {
+                    merger.add_node(Node {
+                        theirs: Some(theirs),
+                        ..Node::default()
+                    });
+                }
+            }
+        }
+
+        merger
+    }
+
+    fn add_node(&mut self, node: Node) {
+        let index = self.nodes.len();
+
+        if let Some(base) = node.base {
+            self.base_nodes.insert(base, index);
+        }
+        if let Some(ours) = node.ours {
+            self.ours_nodes.insert(ours, index);
+        }
+        if let Some(theirs) = node.theirs {
+            self.theirs_nodes.insert(theirs, index);
+        }
+
+        self.nodes.push(node);
+    }
+
+    fn instance(&self, id: Ref) -> &'a Instance {
+        self.dom.get_by_ref(id).unwrap()
+    }
+
+    fn find_added_by_both(&self, theirs: Ref) -> Option<usize> {
+        let instance = self.instance(theirs);
+        let parent = self.theirs_nodes[&instance.parent()];
+        let ours_parent = self.nodes[parent].ours?;
+
+        self.instance(ours_parent)
+            .children()
+            .iter()
+            .map(|child| self.ours_nodes[child])
+            .find(|&node| {
+                let candidate = &self.nodes[node];
+                let ours = self.instance(candidate.ours.unwrap());
+
+                candidate.base.is_none()
+                    && candidate.theirs.is_none()
+                    && ours.name == instance.name
+                    && ours.class == instance.class
+            })
+    }
+
+    fn ours_parent(&self, node: usize) -> Option<usize> {
+        let ours = self.nodes[node].ours?;
+        self.ours_nodes.get(&self.instance(ours).parent()).copied()
+    }
+
+    fn theirs_parent(&self, node: usize) -> Option<usize> {
+        let theirs = self.nodes[node].theirs?;
+        self.theirs_nodes
+            .get(&self.instance(theirs).parent())
+            .copied()
+    }
+
+    fn base_parent(&self, node: usize) -> Option<usize> {
+        let base = self.nodes[node].base?;
+        self.base_nodes.get(&self.instance(base).parent()).copied()
+    }
+
+    /// Decides which instances are part of the merged tree. Parents are
+    /// always decided before their children.
+    fn decide_existence(&mut self) {
+        let order: Vec<usize> = self
+            .ours_order
+            .iter()
+            .map(|ours| self.ours_nodes[ours])
+            .chain(
+                self.theirs_order
+                    .iter()
+                    .map(|theirs| self.theirs_nodes[theirs]),
+            )
+            .collect();
+
+        for (position, node) in order.into_iter().enumerate() {
+            if position == 0 {
+                self.nodes[node].exists = true;
+                continue;
+            }
+
+            let Node {
+                base, ours, theirs, ..
+            } = self.nodes[node];
+
+            let exists = match (base, ours, theirs) {
+                (_, Some(_), Some(_)) => true,
+                (None, Some(_), None) => self.parent_exists(self.ours_parent(node)),
+                (None, None, Some(_)) => self.parent_exists(self.theirs_parent(node)),
+
+                // Instances that were deleted along with their parent follow
+                // whatever happened to the parent.
+                (Some(base), Some(_), None) => {
+                    if self.is_deletion_root(node, Side::Theirs) {
+                        let modified = self.ours_modified.contains(&base);
+                        if modified {
+                            self.conflicts
+                                .push((node, ConflictKind::Deleted(Side::Theirs)));
+                        }
+                        modified
+                    } else {
+                        self.parent_exists(self.ours_parent(node))
+                    }
+                }
+                (Some(base), None, Some(_)) => {
+                    if self.is_deletion_root(node, Side::Ours)
+                        && self.theirs_modified.contains(&base)
+                    {
+                        self.conflicts
+                            .push((node, ConflictKind::Deleted(Side::Ours)));
+                    }
+                    false
+                }
+                _ => false,
+            };
+
+            self.nodes[node].exists = exists;
+        }
+    }
+
+    fn parent_exists(&self, parent: Option<usize>) -> bool {
+        parent.map_or(false, |parent| self.nodes[parent].exists)
+    }
+
+    /// Tells whether the given side deleted this instance itself, rather than
+    /// one of its ancestors.
+    fn is_deletion_root(&self, node: usize, side: Side) -> bool {
+        match self.base_parent(node) {
+            Some(parent) => match side {
+                Side::Ours => self.nodes[parent].ours.is_some(),
+                Side::Theirs => self.nodes[parent].theirs.is_some(),
+            },
+            None => true,
+        }
+    }
+
+    /// Decides where each instance goes. If only one side moved an instance,
+    /// it goes where that side put it.
+    fn decide_parents(&mut self) {
+        for node in 1..self.nodes.len() {
+            if !self.nodes[node].exists {
+                continue;
+            }
+
+            let base_parent = self.base_parent(node);
+            let (preferred, fallback) = match (self.ours_parent(node), self.theirs_parent(node)) {
+                (Some(ours), Some(theirs)) => {
+                    if ours == theirs || Some(theirs) == base_parent {
+                        (ours, Some(theirs))
+                    } else if Some(ours) == base_parent {
+                        (theirs, Some(ours))
+                    } else {
+                        self.add_moved_conflict(node);
+                        (ours, Some(theirs))
+                    }
+                }
+                (Some(parent), None) | (None, Some(parent)) => (parent, None),
+                (None, None) => continue,
+            };
+
+            let parent = Some(preferred)
+                .into_iter()
+                .chain(fallback)
+                .find(|&parent| self.nodes[parent].exists);
+
+            // Both sides can delete the parent the other side put an instance
+            // in, which leaves it where that parent used to be.
+            self.nodes[node].parent = Some(match parent {
+                Some(parent) => parent,
+                None => {
+                    self.add_moved_conflict(node);
+                    self.surviving_ancestor(preferred)
+                }
+            });
+        }
+
+        // Each side moving an instance into the other one can create a cycle.
+        // Our side wins in that case too, so the instance in the cycle that
+        // got its parent from their side goes back to our parent. Instances
+        // that are only cut off because they're inside the cycle are left
+        // where they are.
+        for node in 1..self.nodes.len() {
+            if !self.nodes[node].exists || self.reaches_root(node) {
+                continue;
+            }
+
+            if let Some(ours_parent) = self.ours_parent(node) {
+                if self.nodes[node].parent != Some(ours_parent) {
+                    self.nodes[node].parent = Some(self.surviving_ancestor(ours_parent));
+                    self.add_moved_conflict(node);
+                }
+            }
+        }
+
+        // Falling back to where deleted parents used to be can still leave
+        // instances cut off, which go in the root instead of disappearing.
+        for node in 1..self.nodes.len() {
+            if self.nodes[node].exists && !self.reaches_root(node) {
+                self.nodes[node].parent = Some(0);
+                self.add_moved_conflict(node);
+            }
+        }
+    }
+
+    /// Finds the closest instance to `node` that's part of the merged tree,
+    /// starting with `node` itself and going up through where its ancestors
+    /// were in the base tree, or on either side for added instances.
+    fn surviving_ancestor(&self, node: usize) -> usize {
+        let mut current = node;
+
+        for _ in 0..self.nodes.len() {
+            if self.nodes[current].exists {
+                return current;
+            }
+
+            let parent = self
+                .base_parent(current)
+                .or_else(|| self.ours_parent(current))
+                .or_else(|| self.theirs_parent(current));
+
+            match parent {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        0
+    }
+
+    /// Records that an instance was moved differently by each side, unless
+    /// that's already been recorded.
+    fn add_moved_conflict(&mut self, node: usize) {
+        let recorded = self
+            .conflicts
+            .iter()
+            .any(|(other, kind)| *other == node && matches!(kind, ConflictKind::Moved));
+
+        if !recorded {
+            self.conflicts.push((node, ConflictKind::Moved));
+        }
+    }
+
+    fn reaches_root(&self, node: usize) -> bool {
+        let mut current = node;
+
+        for _ in 0..self.nodes.len() {
+            match self.nodes[current].parent {
+                Some(0) => return true,
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+
+        false
+    }
+
+    /// Creates the merged instances and returns the root of the merged tree.
+    fn build(&mut self) -> InstanceBuilder {
+        let mut builders: Vec<Option<InstanceBuilder>> = Vec::with_capacity(self.nodes.len());
+
+        for node in 0..self.nodes.len() {
+            let builder = if self.nodes[node].exists {
+                let builder = self.new_builder(node);
+                self.nodes[node].merged = Some(builder.referent());
+                Some(builder)
+            } else {
+                None
+            };
+
+            builders.push(builder);
+        }
+
+        // Properties can refer to other merged instances, so they're only
+        // filled in once every merged instance has a referent.
+        for (node, builder) in builders.iter_mut().enumerate() {
+            if let Some(builder) = builder {
+                let properties = self.merge_properties(node);
+                builder.add_properties(properties);
+            }
+        }
+
+        // Children keep the order our side has them in, followed by children
+        // that only their side has.
+        let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut placed = HashSet::new();
+
+        let order: Vec<usize> = self
+            .ours_order
+            .iter()
+            .map(|ours| self.ours_nodes[ours])
+            .chain(
+                self.theirs_order
+                    .iter()
+                    .map(|theirs| self.theirs_nodes[theirs]),
+            )
+            .collect();
+
+        for node in order {
+            if let Some(parent) = self.nodes[node].parent {
+                if self.nodes[node].exists && placed.insert(node) {
+                    children.entry(parent).or_default().push(node);
+                }
+            }
+        }
+
+        assemble(0, &mut builders, &children)
+    }
+
+    fn new_builder(&self, node: usize) -> InstanceBuilder {
+        let Node {
+            base, ours, theirs, ..
+        } = self.nodes[node];
+
+        let ours = ours.map(|id| self.instance(id));
+        let theirs = theirs.map(|id| self.instance(id));
+
+        let class = match (ours, theirs) {
+            (Some(instance), _) | (None, Some(instance)) => instance.class.as_str(),
+            (None, None) => unreachable!("merged instances exist on at least one side"),
+        };
+
+        // Matched instances always have the same name, except for the roots.
+        let name = match (base.map(|id| self.instance(id)), ours, theirs) {
+            (Some(base), Some(ours), Some(theirs)) if ours.name == base.name => &theirs.name,
+            (_, Some(ours), _) => &ours.name,
+            (_, None, Some(theirs)) => &theirs.name,
+            (_, None, None) => unreachable!("merged instances exist on at least one side"),
+        };
+
+        InstanceBuilder::new(class).with_name(name.as_str())
+    }
+
+    fn merge_properties(&mut self, node: usize) -> Vec<(String, Variant)> {
+        let Node {
+            base, ours, theirs, ..
+        } = self.nodes[node];
+
+        let base = base.map(|id| self.instance(id));
+        let ours = ours.map(|id| self.instance(id));
+        let theirs = theirs.map(|id| self.instance(id));
+
+        let (ours, theirs) = match (ours, theirs) {
+            (Some(ours), Some(theirs)) => (ours, theirs),
+            (Some(ours), None) => return self.copy_properties(ours, Side::Ours),
+            (None, Some(theirs)) => return self.copy_properties(theirs, Side::Theirs),
+            (None, None) => return Vec::new(),
+        };
+
+        let mut names: Vec<&String> = ours
+            .properties
+            .keys()
+            .chain(theirs.properties.keys())
+            .chain(base.into_iter().flat_map(|base| base.properties.keys()))
+            .collect();
+        names.sort();
+        names.dedup();
+
+        let mut properties = Vec::new();
+
+        for name in names {
+            let base_value = base.and_then(|base| property_or_default(base, name));
+            let ours_value = property_or_default(ours, name);
+            let theirs_value = property_or_default(theirs, name);
+
+            let base_key = self.value_key(base_value, &self.base_nodes);
+            let ours_key = self.value_key(ours_value, &self.ours_nodes);
+            let theirs_key = self.value_key(theirs_value, &self.theirs_nodes);
+
+            let side = if ours_key == theirs_key || theirs_key == base_key {
+                Side::Ours
+            } else if ours_key == base_key {
+                Side::Theirs
+            } else {
+                self.conflicts.push((
+                    node,
+                    ConflictKind::Property(Box::new(PropertyConflict {
+                        name: name.clone(),
+                        base: base_value.cloned(),
+                        ours: ours_value.cloned(),
+                        theirs: theirs_value.cloned(),
+                    })),
+                ));
+                Side::Ours
+            };
+
+            let value = match side {
+                Side::Ours => ours.properties.get(name),
+                Side::Theirs => theirs.properties.get(name),
+            };
+
+            if let Some(value) = value {
+                properties.push((name.clone(), self.remap(value, side)));
+            }
+        }
+
+        properties
+    }
+
+    fn copy_properties(&self, instance: &Instance, side: Side) -> Vec<(String, Variant)> {
+        instance
+            .properties
+            .iter()
+            .map(|(name, value)| (name.clone(), self.remap(value, side)))
+            .collect()
+    }
+
+    fn value_key<'b>(
+        &self,
+        value: Option<&'b Variant>,
+        nodes: &HashMap<Ref, usize>,
+    ) -> ValueKey<'b> {
+        match value {
+            None => ValueKey::Empty,
+            Some(value) if is_empty_value(value) => ValueKey::Empty,
+            Some(Variant::Ref(referent)) => match nodes.get(referent) {
+                Some(&node) => ValueKey::Node(node),
+                None => ValueKey::Outside(*referent),
+            },
+            Some(value) => ValueKey::Value(value),
+        }
+    }
+
+    /// Points references to instances in one of the merged trees at their
+    /// merged copies. References to instances outside of the merged trees
+    /// are left alone.
+    fn remap(&self, value: &Variant, side: Side) -> Variant {
+        let nodes = match side {
+            Side::Ours => &self.ours_nodes,
+            Side::Theirs => &self.theirs_nodes,
+        };
+
+        match value {
+            Variant::Ref(referent) => match nodes.get(referent) {
+                Some(&node) => Variant::Ref(self.nodes[node].merged.unwrap_or_else(Ref::none)),
+                None => Variant::Ref(*referent),
+            },
+            _ => value.clone(),
+        }
+    }
+
+    fn finish_conflict(&self, node: usize, kind: ConflictKind) -> Conflict {
+        let Node {
+            base,
+            ours,
+            theirs,
+            merged,
+            ..
+        } = self.nodes[node];
+
+        Conflict {
+            kind,
+            base,
+            ours,
+            theirs,
+            merged,
+            theirs_parent: self
+                .theirs_parent(node)
+                .and_then(|parent| self.nodes[parent].merged),
+        }
+    }
+}
+
+fn assemble(
+    node: usize,
+    builders: &mut Vec<Option<InstanceBuilder>>,
+    children: &HashMap<usize, Vec<usize>>,
+) -> InstanceBuilder {
+    let mut builder = builders[node].take().unwrap();
+
+    for &child in children.get(&node).map(Vec::as_slice).unwrap_or(&[]) {
+        builder.add_child(assemble(child, builders, children));
+    }
+
+    builder
+}
+
+fn property_or_default<'a>(instance: &'a Instance, name: &str) -> Option<&'a Variant> {
+    instance
+        .properties
+        .get(name)
+        .or_else(|| find_default_property(&instance.class, name))
+}
+
+/// Finds the instances in the base tree that one side changed, either
+/// directly or by changing one of their descendants.
+fn modified_instances(dom: &WeakDom, diff: &TreeDiff<'_>) -> HashSet<Ref> {
+    let mut modified = HashSet::new();
+    let mut mark = |id: Option<Ref>| {
+        let mut current = id;
+
+        while let Some(id) = current {
+            if !modified.insert(id) {
+                break;
+            }
+
+            current = dom
+                .get_by_ref(id)
+                .map(|instance| instance.parent())
+                .filter(|parent| parent.is_some());
+        }
+    };
+
+    for changed in &diff.changed {
+        mark(diff.matched_old(changed.new));
+    }
+
+    for &added in &diff.added {
+        mark(diff.matched_old(diff.new_instance(added).parent()));
+    }
+
+    for &removed in &diff.removed {
+        mark(Some(diff.old_instance(removed).parent()));
+    }
+
+    for &(old, new) in &diff.moved {
+        mark(Some(old));
+        mark(diff.matched_old(diff.new_instance(new).parent()));
+    }
+
+    modified
+}
@@ -0,0 +1,60 @@
+//! Helpers for looking up classes and properties in the reflection database.
+
+use rbx_dom_weak::types::Variant;
+use rbx_reflection::{ClassDescriptor, PropertyDescriptor, PropertyKind};
+
+/// Iterates over the descriptor for the given class followed by all of its
+/// superclasses, ending with `Instance`.
+pub fn class_hierarchy(
+    class_name: &str,
+) -> impl Iterator<Item = &'static ClassDescriptor<'static>> {
+    let database = rbx_reflection_database::get();
+    let mut current = database.classes.get(class_name);
+
+    std::iter::from_fn(move || {
+        let descriptor = current?;
+        current = descriptor
+            .superclass
+            .as_ref()
+            .and_then(|superclass| database.classes.get(superclass.as_ref()));
+
+        Some(descriptor)
+    })
+}
+
+/// Tells whether instances of the given class are also instances of
+/// `superclass_name`, like `Instance:IsA` in Roblox. Classes that are missing
+/// from the reflection database only match themselves.
+pub fn is_a(class_name: &str, superclass_name: &str) -> bool {
+    class_name == superclass_name
+        || class_hierarchy(class_name).any(|class| class.name == superclass_name)
+}
+
+/// Finds the descriptor for the property with the given name on the given
+/// class or any of its superclasses.
+pub fn find_property_descriptor(
+    class_name: &str,
+    property_name: &str,
+) -> Option<&'static PropertyDescriptor<'static>> {
+    class_hierarchy(class_name).find_map(|class| class.properties.get(property_name))
+}
+
+/// Like `find_property_descriptor`, but follows aliases like `size` or
+/// `archivable` to the canonical property that actually holds the value.
+pub fn find_canonical_property_descriptor(
+    class_name: &str,
+    property_name: &str,
+) -> Option<&'static PropertyDescriptor<'static>> {
+    let descriptor = find_property_descriptor(class_name, property_name)?;
+
+    match &descriptor.kind {
+        PropertyKind::Alias { alias_for } => find_property_descriptor(class_name, alias_for),
+        _ => Some(descriptor),
+    }
+}
+
+/// Finds the value a freshly-created instance of the given class would have
+/// for the given property.
+pub fn find_default_property(class_name: &str, property_name: &str) -> Option<&'static Variant> {
+    class_hierarchy(class_name).find_map(|class| class.default_properties.get(property_name))
+}
@@ -0,0 +1,359 @@
+//! Writes instances to the filesystem the same way Rojo expects to find them,
+//! so that `remodel.readProject` or `rojo build` can turn them back into the
+//! same instances.
+
+use std::{collections::HashSet, fs, path::Path};
+
+use anyhow::{anyhow, bail, Context};
+use rbx_dom_weak::{
+    types::{Ref, Variant},
+    Instance, WeakDom,
+};
+use rbx_reflection::DataType;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+use crate::{
+    reflection::{find_canonical_property_descriptor, find_default_property},
+    sniff_type::DocumentType,
+    value::type_from_str,
+    variant_json::variant_to_json,
+};
+
+/// How an instance is laid out on the filesystem.
+#[derive(Debug, Clone, Copy)]
+enum Layout {
+    /// A Lua file, or a directory with an init script if the script has
+    /// children. Holds the extension of the file.
+    Script(&'static str),
+
+    /// A `.txt` file holding the value of a StringValue.
+    Text,
+
+    /// A directory, with the class of the instance in `init.meta.json` unless
+    /// it's a Folder.
+    Directory,
+
+    /// A model file containing the instance and all of its descendants.
+    Model,
+}
+
+/// Writes the instance with the given ID to the directory at `path`, with its
+/// children as files and directories inside of it.
+pub fn write_dir_tree(
+    dom: &WeakDom,
+    id: Ref,
+    path: &Path,
+    model_type: DocumentType,
+) -> anyhow::Result<()> {
+    let writer = DirTreeWriter { dom, model_type };
+    let instance = dom.get_by_ref(id).unwrap();
+
+    let layout = match writer.layout(instance) {
+        Layout::Script(extension) => Layout::Script(extension),
+        _ => Layout::Directory,
+    };
+
+    // The root can't fall back to a model, so properties that can't be
+    // written are left out instead.
+    let (meta, _) = writer.meta(instance, layout);
+    writer
+        .check_children(instance)
+        .with_context(|| format!("Cannot write {} as a directory", instance.name))?;
+
+    writer.write_directory(instance, layout, &meta, path)
+}
+
+struct DirTreeWriter<'a> {
+    dom: &'a WeakDom,
+    model_type: DocumentType,
+}
+
+impl<'a> DirTreeWriter<'a> {
+    fn layout(&self, instance: &Instance) -> Layout {
+        match instance.class.as_str() {
+            "Script" => Layout::Script(".server.lua"),
+            "LocalScript" => Layout::Script(".client.lua"),
+            "ModuleScript" => Layout::Script(".lua"),
+            "StringValue" if instance.children().is_empty() => Layout::Text,
+            "Folder" => Layout::Directory,
+            _ if !instance.children().is_empty() => Layout::Directory,
+            _ => Layout::Model,
+        }
+    }
+
+    /// Writes a child of an instance that is being written as a directory.
+    /// Instances that can't be represented as files and directories, like
+    /// ones with properties that can't be written to a `.meta.json` file, are
+    /// written as models instead.
+    fn write_child(&self, instance: &Instance, parent_path: &Path) -> anyhow::Result<()> {
+        let layout = self.layout(instance);
+
+        let meta = match layout {
+            Layout::Model => None,
+            _ => match self.meta(instance, layout) {
+                (meta, true) => Some(meta),
+                (_, false) => None,
+            },
+        };
+
+        let meta = match meta {
+            Some(meta) if self.check_children(instance).is_ok() => meta,
+            _ => return self.write_model(instance, parent_path),
+        };
+
+        match layout {
+            Layout::Script(extension) if instance.children().is_empty() => {
+                let source = string_property(instance, "Source");
+                let file_path = parent_path.join(format!("{}{}", instance.name, extension));
+                write_file(&file_path, source)?;
+                self.write_meta(
+                    &meta,
+                    &parent_path.join(format!("{}.meta.json", instance.name)),
+                )
+            }
+            Layout::Text => {
+                let value = string_property(instance, "Value");
+                write_file(&parent_path.join(format!("{}.txt", instance.name)), value)?;
+                self.write_meta(
+                    &meta,
+                    &parent_path.join(format!("{}.meta.json", instance.name)),
+                )
+            }
+            _ => self.write_directory(instance, layout, &meta, &parent_path.join(&instance.name)),
+        }
+    }
+
+    fn write_directory(
+        &self,
+        instance: &Instance,
+        layout: Layout,
+        meta: &JsonMap<String, JsonValue>,
+        path: &Path,
+    ) -> anyhow::Result<()> {
+        fs::create_dir_all(path)
+            .with_context(|| format!("Could not create directory {}", path.display()))?;
+
+        if let Layout::Script(extension) = layout {
+            let source = string_property(instance, "Source");
+            write_file(&path.join(format!("init{}", extension)), source)?;
+        }
+
+        self.write_meta(meta, &path.join("init.meta.json"))?;
+
+        for &child_id in instance.children() {
+            let child = self.dom.get_by_ref(child_id).unwrap();
+            self.write_child(child, path)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_model(&self, instance: &Instance, parent_path: &Path) -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+
+        let extension = match self.model_type {
+            DocumentType::Xml => {
+                let options = rbx_xml::EncodeOptions::new()
+                    .property_behavior(rbx_xml::EncodePropertyBehavior::WriteUnknown);
+
+                rbx_xml::to_writer(&mut buffer, self.dom, &[instance.referent()], options)
+                    .map_err(|err| anyhow!("Could not encode {}: {}", instance.name, err))?;
+                "rbxmx"
+            }
+            DocumentType::Binary => {
+                rbx_binary::to_writer(&mut buffer, self.dom, &[instance.referent()])
+                    .map_err(|err| anyhow!("Could not encode {}: {:?}", instance.name, err))?;
+                "rbxm"
+            }
+        };
+
+        write_file(
+            &parent_path.join(format!("{}.{}", instance.name, extension)),
+            &buffer,
+        )
+    }
+
+    fn write_meta(&self, meta: &JsonMap<String, JsonValue>, path: &Path) -> anyhow::Result<()> {
+        if meta.is_empty() {
+            return Ok(());
+        }
+
+        let mut contents = serde_json::to_string_pretty(meta)?;
+        contents.push('\n');
+        write_file(path, contents)
+    }
+
+    /// Builds the contents of the `.meta.json` file for an instance, which
+    /// holds its class if it can't be told from the file itself, and all of
+    /// its properties and attributes that aren't set to their default value.
+    ///
+    /// Also tells whether every property and attribute could be written, since
+    /// values like references to other instances can't be.
+    fn meta(&self, instance: &Instance, layout: Layout) -> (JsonMap<String, JsonValue>, bool) {
+        let mut meta = JsonMap::new();
+        let mut properties = JsonMap::new();
+        let mut is_complete = true;
+
+        if let Layout::Directory = layout {
+            if instance.class != "Folder" {
+                meta.insert(
+                    "className".to_owned(),
+                    JsonValue::String(instance.class.clone()),
+                );
+            }
+        }
+
+        let mut names: Vec<_> = instance.properties.keys().collect();
+        names.sort();
+
+        for name in names {
+            let value = &instance.properties[name];
+
+            let is_file_contents = match layout {
+                Layout::Script(_) => name == "Source",
+                Layout::Text => name == "Value",
+                _ => false,
+            };
+
+            if is_file_contents
+                || matches!(value, Variant::Ref(referent) if referent.is_none())
+                || matches!(value, Variant::Tags(tags) if tags.iter().next().is_none())
+                || matches!(value, Variant::Attributes(attributes) if attributes.iter().next().is_none())
+                || find_default_property(&instance.class, name) == Some(value)
+            {
+                continue;
+            }
+
+            if let Variant::Attributes(attributes) = value {
+                let mut json_attributes = JsonMap::new();
+
+                for (attribute_name, attribute_value) in attributes.iter() {
+                    let json = match attribute_value {
+                        Variant::Bool(_) | Variant::String(_) | Variant::Float64(_) => {
+                            variant_to_json(attribute_value)
+                        }
+                        _ => explicit_json(attribute_value),
+                    };
+
+                    match json {
+                        Some(json) => {
+                            json_attributes.insert(attribute_name.clone(), json);
+                        }
+                        None => is_complete = false,
+                    }
+                }
+
+                if !json_attributes.is_empty() {
+                    meta.insert("attributes".to_owned(), JsonValue::Object(json_attributes));
+                }
+
+                continue;
+            }
+
+            match property_to_json(&instance.class, name, value) {
+                Some((property_name, json)) => {
+                    properties.insert(property_name, json);
+                }
+                None => is_complete = false,
+            }
+        }
+
+        if !properties.is_empty() {
+            meta.insert("properties".to_owned(), JsonValue::Object(properties));
+        }
+
+        (meta, is_complete)
+    }
+
+    /// Makes sure that every child of the instance can be written to its own
+    /// file without clashing with its siblings.
+    fn check_children(&self, instance: &Instance) -> anyhow::Result<()> {
+        let mut seen = HashSet::new();
+
+        for &child_id in instance.children() {
+            let child = self.dom.get_by_ref(child_id).unwrap();
+            check_file_name(&child.name)?;
+
+            // Many filesystems don't tell apart names that only differ in case.
+            if !seen.insert(child.name.to_lowercase()) {
+                bail!("it has more than one child named {}", child.name);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_file_name(name: &str) -> anyhow::Result<()> {
+    const FORBIDDEN_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+    let lowercase = name.to_lowercase();
+
+    if name.is_empty()
+        || name.ends_with('.')
+        || name.ends_with(' ')
+        || name.contains(|c: char| FORBIDDEN_CHARS.contains(&c) || c.is_control())
+    {
+        bail!("{:?} is not a valid file name", name);
+    }
+
+    // These names would be read back as init files or with the wrong class.
+    if lowercase == "init"
+        || lowercase.starts_with("init.")
+        || lowercase.ends_with(".server")
+        || lowercase.ends_with(".client")
+    {
+        bail!("{:?} has a special meaning to Rojo", name);
+    }
+
+    Ok(())
+}
+
+fn string_property<'a>(instance: &'a Instance, name: &str) -> &'a str {
+    match instance.properties.get(name) {
+        Some(Variant::String(value)) => value,
+        _ => "",
+    }
+}
+
+fn write_file(path: &Path, contents: impl AsRef<[u8]>) -> anyhow::Result<()> {
+    fs::write(path, contents).with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// Turns a property into the name and value it's written with in a
+/// `.meta.json` file. The type of the value is left out when it can be
+/// inferred from the property, like Rojo does.
+fn property_to_json(class_name: &str, name: &str, value: &Variant) -> Option<(String, JsonValue)> {
+    let descriptor = find_canonical_property_descriptor(class_name, name)?;
+
+    let json = match (&descriptor.data_type, value) {
+        (DataType::Enum(enum_name), Variant::Enum(value)) => {
+            let item_name = rbx_reflection_database::get()
+                .enums
+                .get(enum_name.as_ref())?
+                .items
+                .iter()
+                .find(|(_, item_value)| **item_value == value.to_u32())
+                .map(|(item_name, _)| item_name.to_string());
+
+            match item_name {
+                Some(item_name) => JsonValue::String(item_name),
+                None => JsonValue::from(value.to_u32()),
+            }
+        }
+        (DataType::Value(ty), _) if *ty == value.ty() => variant_to_json(value)?,
+        _ => explicit_json(value)?,
+    };
+
+    Some((descriptor.name.to_string(), json))
+}
+
+/// Writes a value along with its type, like `{"Vector3": [1, 2, 3]}`.
+fn explicit_json(value: &Variant) -> Option<JsonValue> {
+    let type_name = format!("{:?}", value.ty());
+    type_from_str(&type_name)?;
+
+    let mut object = JsonMap::new();
+    object.insert(type_name, variant_to_json(value)?);
+    Some(JsonValue::Object(object))
+}
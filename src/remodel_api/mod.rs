@@ -1,17 +1,23 @@
+mod dir_tree;
 mod json;
+mod project;
 mod remodel;
+mod require;
 
 use mlua::Lua;
 
 pub use json::Json;
 pub use remodel::Remodel;
 
+use require::inject_require;
+
 pub struct RemodelApi;
 
 impl RemodelApi {
     pub fn inject(context: &Lua) -> mlua::Result<()> {
         context.globals().set("remodel", Remodel)?;
         context.globals().set("json", Json)?;
+        inject_require(context)?;
 
         Ok(())
     }
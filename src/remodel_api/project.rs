@@ -0,0 +1,777 @@
+//! Builds instances out of Rojo projects, following the same rules that
+//! `rojo build` uses to turn files into instances.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context};
+use rbx_dom_weak::{
+    types::{
+        Attributes, BrickColor, CFrame, Color3, Color3uint8, Enum, Matrix3, NumberRange, Ref, Tags,
+        UDim, UDim2, Variant, VariantType, Vector2, Vector2int16, Vector3, Vector3int16,
+    },
+    InstanceBuilder, WeakDom,
+};
+use rbx_reflection::{ClassTag, DataType};
+use serde_json::Value as JsonValue;
+
+use crate::{reflection::find_canonical_property_descriptor, value::type_from_str};
+
+use super::remodel::{decode_tree, detect_document_type};
+
+/// Builds the project at the given path, which can either be a project file
+/// or a directory containing a `default.project.json` file.
+///
+/// The returned tree has a placeholder root with the root of the project as
+/// its only child.
+pub fn build_project(path: &Path) -> anyhow::Result<WeakDom> {
+    let project_path = if path.is_dir() {
+        path.join("default.project.json")
+    } else {
+        path.to_owned()
+    };
+
+    let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+    let root_ref = dom.root_ref();
+    build_project_file(&mut dom, root_ref, &project_path)?;
+
+    Ok(dom)
+}
+
+fn build_project_file(dom: &mut WeakDom, parent: Ref, project_path: &Path) -> anyhow::Result<Ref> {
+    let contents = fs::read_to_string(project_path)
+        .with_context(|| format!("Could not read project {}", project_path.display()))?;
+    let project: JsonValue = serde_json::from_str(&contents)
+        .with_context(|| format!("Could not parse project {}", project_path.display()))?;
+
+    let name = project
+        .get("name")
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| anyhow!("Project {} is missing a name", project_path.display()))?;
+    let tree = project
+        .get("tree")
+        .ok_or_else(|| anyhow!("Project {} is missing a tree", project_path.display()))?;
+    let base_path = project_path.parent().unwrap_or_else(|| Path::new(""));
+
+    let id = build_project_node(dom, parent, name, tree, base_path)
+        .with_context(|| format!("Could not build project {}", project_path.display()))?;
+
+    id.ok_or_else(|| {
+        anyhow!(
+            "The root of project {} does not produce an instance",
+            project_path.display()
+        )
+    })
+}
+
+fn build_project_node(
+    dom: &mut WeakDom,
+    parent: Ref,
+    name: &str,
+    node: &JsonValue,
+    base_path: &Path,
+) -> anyhow::Result<Option<Ref>> {
+    let node = node
+        .as_object()
+        .ok_or_else(|| anyhow!("Project node {} must be an object", name))?;
+    let class_name = node.get("$className").and_then(JsonValue::as_str);
+
+    let id = match node.get("$path") {
+        Some(path) => {
+            let (path, optional) = match path {
+                JsonValue::String(path) => (path.as_str(), false),
+                JsonValue::Object(object) => match object.get("optional") {
+                    Some(JsonValue::String(path)) => (path.as_str(), true),
+                    _ => bail!("$path of {} must be a string", name),
+                },
+                _ => bail!("$path of {} must be a string", name),
+            };
+
+            let full_path = base_path.join(path);
+            if optional && !full_path.exists() {
+                return Ok(None);
+            }
+
+            let id = build_path(dom, parent, &full_path)?
+                .ok_or_else(|| anyhow!("{} does not produce an instance", full_path.display()))?;
+
+            let instance = dom.get_by_ref_mut(id).unwrap();
+            instance.name = name.to_owned();
+
+            match class_name {
+                Some(class_name) => {
+                    if instance.class != "Folder" {
+                        bail!(
+                            "$className cannot be used on {} because its $path is not a directory",
+                            name
+                        );
+                    }
+
+                    instance.class = class_name.to_owned();
+                }
+
+                // Directories named after a service, like a ServerScriptService
+                // node pointing at `src/server`, become that service.
+                None if instance.class == "Folder" && is_service(name) => {
+                    instance.class = name.to_owned();
+                }
+
+                None => {}
+            }
+
+            id
+        }
+        None => {
+            let class_name = match class_name {
+                Some(class_name) => class_name,
+                None if is_service(name) => name,
+                None => bail!(
+                    "Project node {} needs a $className or $path, because it is not a service",
+                    name
+                ),
+            };
+
+            dom.insert(parent, InstanceBuilder::new(class_name).with_name(name))
+        }
+    };
+
+    if let Some(properties) = node.get("$properties") {
+        apply_properties(dom, id, properties)
+            .with_context(|| format!("Invalid $properties for {}", name))?;
+    }
+
+    if let Some(attributes) = node.get("$attributes") {
+        apply_attributes(dom, id, attributes)
+            .with_context(|| format!("Invalid $attributes for {}", name))?;
+    }
+
+    for (child_name, child) in node {
+        if !child_name.starts_with('$') {
+            build_project_node(dom, id, child_name, child, base_path)?;
+        }
+    }
+
+    Ok(Some(id))
+}
+
+fn is_service(class_name: &str) -> bool {
+    rbx_reflection_database::get()
+        .classes
+        .get(class_name)
+        .map(|class| class.tags.contains(&ClassTag::Service))
+        .unwrap_or(false)
+}
+
+/// Turns a file or directory into an instance. Files that Rojo ignores, like
+/// images, don't produce an instance.
+fn build_path(dom: &mut WeakDom, parent: Ref, path: &Path) -> anyhow::Result<Option<Ref>> {
+    let metadata =
+        fs::metadata(path).with_context(|| format!("Could not read {}", path.display()))?;
+
+    if metadata.is_dir() {
+        return build_directory(dom, parent, path).map(Some);
+    }
+
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow!("{} has an invalid file name", path.display()))?;
+
+    if file_name.ends_with(".meta.json") {
+        return Ok(None);
+    }
+
+    if file_name.ends_with(".project.json") {
+        return build_project_file(dom, parent, path).map(Some);
+    }
+
+    let (name, builder) = if let Some((name, class_name)) = script_name_and_class(file_name) {
+        let source = read_text(path)?;
+        let builder = InstanceBuilder::new(class_name).with_property("Source", source);
+
+        (name, builder)
+    } else if let Some(name) = file_name.strip_suffix(".model.json") {
+        let contents = read_text(path)?;
+        let model: JsonValue = serde_json::from_str(&contents)
+            .with_context(|| format!("Could not parse {}", path.display()))?;
+
+        let id = build_json_model(dom, parent, name, &model)
+            .with_context(|| format!("Invalid model {}", path.display()))?;
+        apply_meta_file(dom, id, &path.with_file_name(format!("{}.meta.json", name)))?;
+
+        return Ok(Some(id));
+    } else if let Some(name) = file_name.strip_suffix(".json") {
+        let contents = read_text(path)?;
+        let value: JsonValue = serde_json::from_str(&contents)
+            .with_context(|| format!("Could not parse {}", path.display()))?;
+        let source = format!("return {}", json_to_lua_source(&value));
+        let builder = InstanceBuilder::new("ModuleScript").with_property("Source", source);
+
+        (name, builder)
+    } else if let Some(name) = file_name.strip_suffix(".txt") {
+        let contents = read_text(path)?;
+        let builder = InstanceBuilder::new("StringValue").with_property("Value", contents);
+
+        (name, builder)
+    } else if let Some(name) = file_name.strip_suffix(".csv") {
+        let contents = read_text(path)?;
+        let table = localization_table_contents(&contents)
+            .with_context(|| format!("Invalid localization table {}", path.display()))?;
+        let builder = InstanceBuilder::new("LocalizationTable").with_property("Contents", table);
+
+        (name, builder)
+    } else if let Some(name) = file_name
+        .strip_suffix(".rbxm")
+        .or_else(|| file_name.strip_suffix(".rbxmx"))
+    {
+        let id = build_model_file(dom, parent, path)?;
+        dom.get_by_ref_mut(id).unwrap().name = name.to_owned();
+        apply_meta_file(dom, id, &path.with_file_name(format!("{}.meta.json", name)))?;
+
+        return Ok(Some(id));
+    } else {
+        return Ok(None);
+    };
+
+    let id = dom.insert(parent, builder.with_name(name));
+    apply_meta_file(dom, id, &path.with_file_name(format!("{}.meta.json", name)))?;
+
+    Ok(Some(id))
+}
+
+fn build_directory(dom: &mut WeakDom, parent: Ref, path: &Path) -> anyhow::Result<Ref> {
+    let nested_project = path.join("default.project.json");
+    if nested_project.is_file() {
+        return build_project_file(dom, parent, &nested_project);
+    }
+
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow!("{} has an invalid directory name", path.display()))?;
+
+    let mut entries = fs::read_dir(path)
+        .with_context(|| format!("Could not read directory {}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, _>>()?;
+    entries.sort();
+
+    // A directory containing an init script becomes that script, and the
+    // rest of its contents become the script's children.
+    let init_script = entries.iter().find_map(|entry| {
+        let file_name = entry.file_name()?.to_str()?;
+        match script_name_and_class(file_name) {
+            Some(("init", class_name)) => Some((entry.clone(), class_name)),
+            _ => None,
+        }
+    });
+
+    let builder = match &init_script {
+        Some((init_path, class_name)) => {
+            InstanceBuilder::new(*class_name).with_property("Source", read_text(init_path)?)
+        }
+        None => InstanceBuilder::new("Folder"),
+    };
+
+    let id = dom.insert(parent, builder.with_name(name));
+    apply_meta_file(dom, id, &path.join("init.meta.json"))?;
+
+    for entry in &entries {
+        let is_init = entry
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.starts_with("init."))
+            .unwrap_or(false);
+
+        if !is_init {
+            build_path(dom, id, entry)?;
+        }
+    }
+
+    Ok(id)
+}
+
+/// Finds the instance name and class of a Lua script from its file name.
+fn script_name_and_class(file_name: &str) -> Option<(&str, &'static str)> {
+    let stem = file_name
+        .strip_suffix(".lua")
+        .or_else(|| file_name.strip_suffix(".luau"))?;
+
+    if let Some(name) = stem.strip_suffix(".server") {
+        Some((name, "Script"))
+    } else if let Some(name) = stem.strip_suffix(".client") {
+        Some((name, "LocalScript"))
+    } else {
+        Some((stem, "ModuleScript"))
+    }
+}
+
+fn read_text(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))
+}
+
+/// Moves the single top-level instance of a model file into `dom`.
+fn build_model_file(dom: &mut WeakDom, parent: Ref, path: &Path) -> anyhow::Result<Ref> {
+    let buffer = fs::read(path).with_context(|| format!("Could not read {}", path.display()))?;
+
+    let mut model = detect_document_type(path, &buffer, "model", "rbxmx", "rbxm")
+        .and_then(|document_type| decode_tree(&buffer, document_type))
+        .map_err(|err| anyhow!("Could not decode {}: {}", path.display(), err))?;
+
+    let children = model.root().children().to_vec();
+    match children.as_slice() {
+        [id] => {
+            model.transfer(*id, dom, parent);
+            Ok(*id)
+        }
+        _ => bail!(
+            "{} must contain exactly one top-level instance, but contains {}",
+            path.display(),
+            children.len()
+        ),
+    }
+}
+
+fn build_json_model(
+    dom: &mut WeakDom,
+    parent: Ref,
+    name: &str,
+    model: &JsonValue,
+) -> anyhow::Result<Ref> {
+    let field = |key: &str| json_model_field(model, key);
+
+    let class_name = field("ClassName")
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| anyhow!("{} is missing a ClassName", name))?;
+    let name = field("Name").and_then(JsonValue::as_str).unwrap_or(name);
+
+    let id = dom.insert(parent, InstanceBuilder::new(class_name).with_name(name));
+
+    if let Some(properties) = field("Properties") {
+        apply_properties(dom, id, properties)?;
+    }
+
+    if let Some(attributes) = field("Attributes") {
+        apply_attributes(dom, id, attributes)?;
+    }
+
+    if let Some(children) = field("Children") {
+        let children = children
+            .as_array()
+            .ok_or_else(|| anyhow!("Children of {} must be an array", name))?;
+
+        // Children without a name are named after their class, like the
+        // model itself is named after its file.
+        for child in children {
+            let child_class_name = json_model_field(child, "ClassName")
+                .and_then(JsonValue::as_str)
+                .unwrap_or(name);
+
+            build_json_model(dom, id, child_class_name, child)?;
+        }
+    }
+
+    Ok(id)
+}
+
+/// Finds a field of a JSON model. Rojo accepts both capitalizations of the
+/// keys, like `ClassName` and `className`.
+fn json_model_field<'a>(model: &'a JsonValue, key: &str) -> Option<&'a JsonValue> {
+    let mut chars = key.chars();
+    let lower = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect::<String>(),
+        None => String::new(),
+    };
+
+    model.get(key).or_else(|| model.get(lower.as_str()))
+}
+
+fn apply_meta_file(dom: &mut WeakDom, id: Ref, meta_path: &Path) -> anyhow::Result<()> {
+    if !meta_path.is_file() {
+        return Ok(());
+    }
+
+    let contents = read_text(meta_path)?;
+    let meta: JsonValue = serde_json::from_str(&contents)
+        .with_context(|| format!("Could not parse {}", meta_path.display()))?;
+
+    if let Some(class_name) = meta.get("className").and_then(JsonValue::as_str) {
+        let instance = dom.get_by_ref_mut(id).unwrap();
+        if instance.class != "Folder" {
+            bail!(
+                "className in {} can only be used on directories without an init script",
+                meta_path.display()
+            );
+        }
+
+        instance.class = class_name.to_owned();
+    }
+
+    if let Some(properties) = meta.get("properties") {
+        apply_properties(dom, id, properties)
+            .with_context(|| format!("Invalid properties in {}", meta_path.display()))?;
+    }
+
+    if let Some(attributes) = meta.get("attributes") {
+        apply_attributes(dom, id, attributes)
+            .with_context(|| format!("Invalid attributes in {}", meta_path.display()))?;
+    }
+
+    Ok(())
+}
+
+fn apply_properties(dom: &mut WeakDom, id: Ref, properties: &JsonValue) -> anyhow::Result<()> {
+    let properties = properties
+        .as_object()
+        .ok_or_else(|| anyhow!("Properties must be an object"))?;
+    let instance = dom.get_by_ref_mut(id).unwrap();
+
+    for (name, value) in properties {
+        let descriptor = find_canonical_property_descriptor(&instance.class, name)
+            .ok_or_else(|| anyhow!("'{}' is not a valid member of {}", name, instance.class))?;
+
+        let value = match explicit_value(value) {
+            Some((ty, inner)) => json_to_variant(ty, inner),
+            None => match &descriptor.data_type {
+                DataType::Value(ty) => json_to_variant(*ty, value),
+                DataType::Enum(enum_name) => json_to_enum(enum_name, value),
+                _ => Err(anyhow!("The type of this property is unknown")),
+            },
+        }
+        .with_context(|| format!("Invalid value for {}.{}", instance.class, name))?;
+
+        instance
+            .properties
+            .insert(descriptor.name.clone().into_owned(), value);
+    }
+
+    Ok(())
+}
+
+fn apply_attributes(dom: &mut WeakDom, id: Ref, attributes: &JsonValue) -> anyhow::Result<()> {
+    let attributes = attributes
+        .as_object()
+        .ok_or_else(|| anyhow!("Attributes must be an object"))?;
+    let instance = dom.get_by_ref_mut(id).unwrap();
+
+    let mut existing = match instance.properties.remove("Attributes") {
+        Some(Variant::Attributes(existing)) => existing,
+        _ => Attributes::new(),
+    };
+
+    for (name, value) in attributes {
+        let value = match (explicit_value(value), value) {
+            (Some((ty, inner)), _) => json_to_variant(ty, inner)?,
+            (None, JsonValue::Bool(value)) => Variant::Bool(*value),
+            (None, JsonValue::Number(_)) => json_to_variant(VariantType::Float64, value)?,
+            (None, JsonValue::String(value)) => Variant::String(value.clone()),
+            (None, _) => bail!("Attribute {} must have an explicit type", name),
+        };
+
+        existing.insert(name.clone(), value);
+    }
+
+    instance
+        .properties
+        .insert("Attributes".to_owned(), Variant::Attributes(existing));
+
+    Ok(())
+}
+
+/// Values can be written with their type, like `{"Vector3": [1, 2, 3]}`, which
+/// is necessary when the type can't be inferred from the property.
+fn explicit_value(value: &JsonValue) -> Option<(VariantType, &JsonValue)> {
+    let object = value.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+
+    let (ty, inner) = object.iter().next()?;
+    Some((type_from_str(ty)?, inner))
+}
+
+fn json_to_enum(enum_name: &str, value: &JsonValue) -> anyhow::Result<Variant> {
+    let descriptor = rbx_reflection_database::get()
+        .enums
+        .get(enum_name)
+        .ok_or_else(|| anyhow!("Unknown enum {}", enum_name))?;
+
+    let item = match value {
+        JsonValue::String(name) => descriptor.items.get(name.as_str()).copied(),
+        JsonValue::Number(number) => number
+            .as_u64()
+            .map(|number| number as u32)
+            .filter(|number| descriptor.items.values().any(|value| value == number)),
+        _ => None,
+    };
+
+    item.map(|value| Variant::Enum(Enum::from_u32(value)))
+        .ok_or_else(|| anyhow!("{} is not a valid item of Enum.{}", value, enum_name))
+}
+
+fn json_to_variant(ty: VariantType, value: &JsonValue) -> anyhow::Result<Variant> {
+    let number = |value: &JsonValue| {
+        value
+            .as_f64()
+            .ok_or_else(|| anyhow!("Expected a number, got {}", value))
+    };
+    let numbers = |value: &JsonValue, count: usize| -> anyhow::Result<Vec<f64>> {
+        match value.as_array() {
+            Some(array) if array.len() == count => array.iter().map(number).collect(),
+            _ => bail!("Expected an array of {} numbers, got {}", count, value),
+        }
+    };
+
+    let variant = match ty {
+        VariantType::Bool => Variant::Bool(
+            value
+                .as_bool()
+                .ok_or_else(|| anyhow!("Expected a boolean, got {}", value))?,
+        ),
+        VariantType::Float32 => Variant::Float32(number(value)? as f32),
+        VariantType::Float64 => Variant::Float64(number(value)?),
+        VariantType::Int32 => Variant::Int32(number(value)? as i32),
+        VariantType::Int64 => Variant::Int64(number(value)? as i64),
+        VariantType::String | VariantType::Content => {
+            let string = value
+                .as_str()
+                .ok_or_else(|| anyhow!("Expected a string, got {}", value))?;
+
+            if ty == VariantType::String {
+                Variant::String(string.to_owned())
+            } else {
+                Variant::Content(string.into())
+            }
+        }
+        VariantType::Enum => Variant::Enum(Enum::from_u32(number(value)? as u32)),
+        VariantType::Vector2 => {
+            let v = numbers(value, 2)?;
+            Variant::Vector2(Vector2::new(v[0] as f32, v[1] as f32))
+        }
+        VariantType::Vector2int16 => {
+            let v = numbers(value, 2)?;
+            Variant::Vector2int16(Vector2int16::new(v[0] as i16, v[1] as i16))
+        }
+        VariantType::Vector3 => {
+            let v = numbers(value, 3)?;
+            Variant::Vector3(Vector3::new(v[0] as f32, v[1] as f32, v[2] as f32))
+        }
+        VariantType::Vector3int16 => {
+            let v = numbers(value, 3)?;
+            Variant::Vector3int16(Vector3int16::new(v[0] as i16, v[1] as i16, v[2] as i16))
+        }
+        VariantType::Color3 => {
+            let v = numbers(value, 3)?;
+            Variant::Color3(Color3::new(v[0] as f32, v[1] as f32, v[2] as f32))
+        }
+        VariantType::Color3uint8 => {
+            let v = numbers(value, 3)?;
+            Variant::Color3uint8(Color3uint8::new(v[0] as u8, v[1] as u8, v[2] as u8))
+        }
+        VariantType::UDim => {
+            let v = numbers(value, 2)?;
+            Variant::UDim(UDim::new(v[0] as f32, v[1] as i32))
+        }
+        VariantType::UDim2 => match value.as_array().map(Vec::as_slice) {
+            Some([x, y]) => {
+                let x = numbers(x, 2)?;
+                let y = numbers(y, 2)?;
+
+                Variant::UDim2(UDim2::new(
+                    UDim::new(x[0] as f32, x[1] as i32),
+                    UDim::new(y[0] as f32, y[1] as i32),
+                ))
+            }
+            _ => bail!("Expected an array of two UDims, got {}", value),
+        },
+        VariantType::NumberRange => {
+            let v = numbers(value, 2)?;
+            Variant::NumberRange(NumberRange::new(v[0] as f32, v[1] as f32))
+        }
+        VariantType::BrickColor => {
+            let brick_color = match value {
+                JsonValue::String(name) => BrickColor::from_name(name),
+                _ => BrickColor::from_number(number(value)? as u16),
+            };
+
+            Variant::BrickColor(
+                brick_color.ok_or_else(|| anyhow!("{} is not a valid BrickColor", value))?,
+            )
+        }
+        VariantType::CFrame => {
+            let position = value
+                .get("position")
+                .ok_or_else(|| anyhow!("CFrame is missing a position"))?;
+            let orientation = value
+                .get("orientation")
+                .and_then(JsonValue::as_array)
+                .ok_or_else(|| anyhow!("CFrame is missing an orientation"))?;
+
+            let position = numbers(position, 3)?;
+            let rows = orientation
+                .iter()
+                .map(|row| numbers(row, 3))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            if rows.len() != 3 {
+                bail!("CFrame orientation must have 3 rows");
+            }
+
+            let row = |row: &[f64]| Vector3::new(row[0] as f32, row[1] as f32, row[2] as f32);
+
+            Variant::CFrame(CFrame::new(
+                row(&position),
+                Matrix3::new(row(&rows[0]), row(&rows[1]), row(&rows[2])),
+            ))
+        }
+        VariantType::Tags => {
+            let tags = value
+                .as_array()
+                .ok_or_else(|| anyhow!("Expected an array of tags, got {}", value))?
+                .iter()
+                .map(|tag| {
+                    tag.as_str()
+                        .map(str::to_owned)
+                        .ok_or_else(|| anyhow!("Expected a string tag, got {}", tag))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            Variant::Tags(Tags::from(tags))
+        }
+        _ => bail!("Values of type {:?} are not supported in projects", ty),
+    };
+
+    Ok(variant)
+}
+
+/// Turns a JSON value into the source of an equivalent Lua value, which is how
+/// Rojo turns JSON files into ModuleScripts.
+fn json_to_lua_source(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null => "nil".to_owned(),
+        JsonValue::Bool(value) => value.to_string(),
+        JsonValue::Number(value) => value.to_string(),
+        JsonValue::String(value) => lua_string_literal(value),
+        JsonValue::Array(values) => {
+            let values: Vec<_> = values.iter().map(json_to_lua_source).collect();
+            format!("{{{}}}", values.join(", "))
+        }
+        JsonValue::Object(entries) => {
+            let entries: Vec<_> = entries
+                .iter()
+                .map(|(key, value)| {
+                    format!(
+                        "[{}] = {}",
+                        lua_string_literal(key),
+                        json_to_lua_source(value)
+                    )
+                })
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+    }
+}
+
+fn lua_string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            // Decimal escapes are padded so that a digit after them isn't
+            // read as part of the escape. Other characters, including
+            // non-ASCII control characters, are already valid UTF-8.
+            c if c.is_ascii_control() => literal.push_str(&format!("\\{:03}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+
+    literal.push('"');
+    literal
+}
+
+/// Turns a CSV file into the JSON that LocalizationTable stores in its
+/// Contents property. The columns `Key`, `Source`, `Context`, and `Example`
+/// have special meaning, and all other columns are locales.
+fn localization_table_contents(csv: &str) -> anyhow::Result<String> {
+    let mut rows = parse_csv(csv).into_iter();
+    let header = rows
+        .next()
+        .ok_or_else(|| anyhow!("Localization tables need a header row"))?;
+
+    let mut entries = Vec::new();
+
+    for row in rows {
+        let mut entry = serde_json::Map::new();
+        let mut values = serde_json::Map::new();
+
+        for (column, cell) in header.iter().zip(row.iter()) {
+            if cell.is_empty() {
+                continue;
+            }
+
+            let key = match column.as_str() {
+                "Key" => "key",
+                "Source" => "source",
+                "Context" => "context",
+                "Example" => "example",
+                locale => {
+                    values.insert(locale.to_owned(), JsonValue::String(cell.clone()));
+                    continue;
+                }
+            };
+
+            entry.insert(key.to_owned(), JsonValue::String(cell.clone()));
+        }
+
+        if !entry.contains_key("key") && !entry.contains_key("source") {
+            continue;
+        }
+
+        entry.insert("values".to_owned(), JsonValue::Object(values));
+        entries.push(JsonValue::Object(entry));
+    }
+
+    Ok(serde_json::to_string(&entries)?)
+}
+
+/// Parses CSV as described in RFC 4180, where cells can be quoted to contain
+/// commas, newlines, and doubled quotes.
+fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', _) => in_quotes = !in_quotes,
+            (',', false) => row.push(std::mem::take(&mut cell)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => cell.push(c),
+        }
+    }
+
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    rows.retain(|row| row.iter().any(|cell| !cell.is_empty()));
+    rows
+}
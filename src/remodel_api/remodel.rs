@@ -1,26 +1,187 @@
 use std::{
     ffi::OsStr,
     fs::{self, File},
-    io::{BufReader, BufWriter, Read},
+    io::Read,
     path::Path,
     sync::Arc,
     time::Duration,
 };
 
-use mlua::{Lua, UserData, UserDataMethods};
-use rbx_dom_weak::{types::VariantType, InstanceBuilder, WeakDom};
+use mlua::{FromLua, Lua, UserData, UserDataMethods};
+use rbx_dom_weak::{
+    types::{Ref, Variant, VariantType},
+    InstanceBuilder, WeakDom,
+};
 use reqwest::{
     header::{ACCEPT, CONTENT_TYPE, COOKIE, USER_AGENT},
     StatusCode,
 };
 
 use crate::{
+    diff::TreeDiff,
+    dom_util::instance_path,
+    merge::{merge, ConflictKind, Side},
+    reflection::{class_hierarchy, find_default_property},
     remodel_context::RemodelContext,
     roblox_api::LuaInstance,
     sniff_type::{sniff_type, DocumentType},
     value::{lua_to_rbxvalue, rbxvalue_to_lua, type_from_str},
 };
 
+use super::{dir_tree::write_dir_tree, project::build_project};
+
+/// Converts the value of a property for `remodel.getProperties` and
+/// `remodel.getDefaultProperties`. Values that Remodel can't represent yet
+/// become nil so that listing properties never fails; their type is still
+/// reported.
+fn property_value_to_lua<'lua>(context: &'lua Lua, value: &Variant) -> mlua::Value<'lua> {
+    match value {
+        Variant::Ref(_) => mlua::Value::Nil,
+        _ => rbxvalue_to_lua(context, value).unwrap_or(mlua::Value::Nil),
+    }
+}
+
+fn property_entry<'lua>(
+    context: &'lua Lua,
+    value: &Variant,
+    lua_value: mlua::Value<'lua>,
+) -> mlua::Result<mlua::Table<'lua>> {
+    let entry = context.create_table()?;
+    entry.set("type", format!("{:?}", value.ty()))?;
+    entry.set("value", lua_value)?;
+
+    Ok(entry)
+}
+
+/// Decides whether the given file should be decoded as an XML or binary
+/// place or model. The contents of the file win, but if they disagree with a
+/// known extension, the file is probably not what the user thinks it is.
+pub(crate) fn detect_document_type(
+    path: &Path,
+    buffer: &[u8],
+    kind: &str,
+    xml_extension: &str,
+    binary_extension: &str,
+) -> mlua::Result<DocumentType> {
+    let expected = path
+        .extension()
+        .and_then(OsStr::to_str)
+        .and_then(|extension| {
+            document_type_from_extension(extension, xml_extension, binary_extension)
+        });
+
+    match (expected, sniff_type(buffer)) {
+        (Some(expected), Some(actual)) if expected != actual => {
+            Err(mlua::Error::external(format!(
+                "{} has the extension of {} {} file, but contains {} {} file",
+                path.display(),
+                article(expected),
+                document_type_name(expected),
+                article(actual),
+                document_type_name(actual),
+            )))
+        }
+        (_, Some(actual)) => Ok(actual),
+
+        // XML files can start with a declaration or whitespace, which we
+        // don't sniff. Trust the extension for those.
+        (Some(expected), None) => Ok(expected),
+
+        (None, None) => Err(mlua::Error::external(format!(
+            "{} is not a Roblox {} file",
+            path.display(),
+            kind
+        ))),
+    }
+}
+
+fn document_type_name(document_type: DocumentType) -> &'static str {
+    match document_type {
+        DocumentType::Xml => "XML",
+        DocumentType::Binary => "binary",
+    }
+}
+
+fn article(document_type: DocumentType) -> &'static str {
+    match document_type {
+        DocumentType::Xml => "an",
+        DocumentType::Binary => "a",
+    }
+}
+
+fn document_type_from_extension(
+    extension: &str,
+    xml_extension: &str,
+    binary_extension: &str,
+) -> Option<DocumentType> {
+    if extension == xml_extension {
+        Some(DocumentType::Xml)
+    } else if extension == binary_extension {
+        Some(DocumentType::Binary)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileKind {
+    Place,
+    Model,
+}
+
+/// Tells places and models apart by the extension of their file.
+fn file_kind(path: &Path) -> mlua::Result<FileKind> {
+    match path.extension().and_then(OsStr::to_str) {
+        Some("rbxl") | Some("rbxlx") => Ok(FileKind::Place),
+        Some("rbxm") | Some("rbxmx") => Ok(FileKind::Model),
+        _ => Err(mlua::Error::external(format!(
+            "{} is not a place or model file path",
+            path.display()
+        ))),
+    }
+}
+
+fn encode_tree(tree: &WeakDom, ids: &[Ref], document_type: DocumentType) -> mlua::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    match document_type {
+        DocumentType::Xml => rbx_xml::to_writer(&mut buffer, tree, ids, xml_encode_options())
+            .map_err(mlua::Error::external)?,
+        DocumentType::Binary => rbx_binary::to_writer(&mut buffer, tree, ids)
+            .map_err(|err| mlua::Error::external(format!("{:?}", err)))?,
+    }
+
+    Ok(buffer)
+}
+
+pub(crate) fn decode_tree(buffer: &[u8], document_type: DocumentType) -> mlua::Result<WeakDom> {
+    match document_type {
+        DocumentType::Xml => {
+            rbx_xml::from_reader(buffer, xml_decode_options()).map_err(mlua::Error::external)
+        }
+        DocumentType::Binary => rbx_binary::from_reader(buffer)
+            .map_err(|err| mlua::Error::external(format!("{:?}", err))),
+    }
+}
+
+/// The instances to save as a model, which can be given either as a single
+/// instance or as a list of instances like the one `readModelFile` returns.
+struct ModelInstances(Vec<LuaInstance>);
+
+impl<'lua> FromLua<'lua> for ModelInstances {
+    fn from_lua(value: mlua::Value<'lua>, context: &'lua Lua) -> mlua::Result<Self> {
+        match value {
+            mlua::Value::Table(table) => table
+                .sequence_values::<LuaInstance>()
+                .collect::<mlua::Result<_>>()
+                .map(ModelInstances),
+            value => {
+                LuaInstance::from_lua(value, context).map(|instance| ModelInstances(vec![instance]))
+            }
+        }
+    }
+}
+
 fn xml_encode_options() -> rbx_xml::EncodeOptions {
     rbx_xml::EncodeOptions::new().property_behavior(rbx_xml::EncodePropertyBehavior::WriteUnknown)
 }
@@ -37,42 +198,61 @@ pub enum UploadPlaceAsset {
 pub struct Remodel;
 
 impl Remodel {
-    fn read_xml_place_file<'lua>(context: &'lua Lua, path: &Path) -> mlua::Result<LuaInstance> {
-        let file = BufReader::new(File::open(path).map_err(mlua::Error::external)?);
-        let source_tree =
-            rbx_xml::from_reader(file, xml_decode_options()).map_err(mlua::Error::external)?;
+    fn read_place_file(context: &Lua, path: &Path) -> mlua::Result<LuaInstance> {
+        let buffer = fs::read(path).map_err(mlua::Error::external)?;
+        let document_type = detect_document_type(path, &buffer, "place", "rbxlx", "rbxl")?;
+        let source_tree = decode_tree(&buffer, document_type)?;
 
         Remodel::import_tree_root(context, source_tree)
     }
 
-    fn read_xml_model_file<'lua>(
-        context: &'lua Lua,
-        path: &Path,
-    ) -> mlua::Result<Vec<LuaInstance>> {
-        let file = BufReader::new(File::open(path).map_err(mlua::Error::external)?);
-        let source_tree =
-            rbx_xml::from_reader(file, xml_decode_options()).map_err(mlua::Error::external)?;
+    fn read_model_file(context: &Lua, path: &Path) -> mlua::Result<Vec<LuaInstance>> {
+        let buffer = fs::read(path).map_err(mlua::Error::external)?;
+        let document_type = detect_document_type(path, &buffer, "model", "rbxmx", "rbxm")?;
+        let source_tree = decode_tree(&buffer, document_type)?;
 
         Remodel::import_tree_children(context, source_tree)
     }
 
-    fn read_binary_place_file<'lua>(context: &'lua Lua, path: &Path) -> mlua::Result<LuaInstance> {
-        let file = BufReader::new(File::open(path).map_err(mlua::Error::external)?);
-        let source_tree = rbx_binary::from_reader(file).map_err(mlua::Error::external)?;
+    /// Reads a place or model file without adding it to the tree that Lua
+    /// sees, for commands that only need to look at the file.
+    pub fn read_file_tree(path: &Path) -> mlua::Result<WeakDom> {
+        let buffer = fs::read(path).map_err(mlua::Error::external)?;
+
+        let document_type = match file_kind(path) {
+            Ok(FileKind::Place) => detect_document_type(path, &buffer, "place", "rbxlx", "rbxl")?,
+            Ok(FileKind::Model) => detect_document_type(path, &buffer, "model", "rbxmx", "rbxm")?,
+            Err(_) => sniff_type(&buffer).ok_or_else(|| {
+                mlua::Error::external(format!(
+                    "{} is not a Roblox place or model file",
+                    path.display()
+                ))
+            })?,
+        };
 
-        Remodel::import_tree_root(context, source_tree)
+        decode_tree(&buffer, document_type)
     }
 
-    fn read_binary_model_file<'lua>(
-        context: &'lua Lua,
-        path: &Path,
-    ) -> mlua::Result<Vec<LuaInstance>> {
-        let file = BufReader::new(File::open(path).map_err(mlua::Error::external)?);
+    fn read_project(context: &Lua, path: &Path) -> mlua::Result<LuaInstance> {
+        let source_tree =
+            build_project(path).map_err(|err| mlua::Error::external(format!("{:#}", err)))?;
 
-        let source_tree = rbx_binary::from_reader(file)
-            .map_err(|err| mlua::Error::external(format!("{:?}", err)))?;
+        let mut instances = Remodel::import_tree_children(context, source_tree)?;
+        Ok(instances.remove(0))
+    }
 
-        Remodel::import_tree_children(context, source_tree)
+    fn detect_file_type(path: &Path) -> mlua::Result<Option<&'static str>> {
+        let mut header = Vec::new();
+        File::open(path)
+            .and_then(|file| file.take(8).read_to_end(&mut header))
+            .map_err(mlua::Error::external)?;
+
+        Ok(
+            sniff_type(&header).map(|document_type| match document_type {
+                DocumentType::Xml => "xml",
+                DocumentType::Binary => "binary",
+            }),
+        )
     }
 
     pub fn import_tree_children(
@@ -116,79 +296,449 @@ impl Remodel {
         Ok(LuaInstance::new(Arc::clone(&master_tree), new_root_ref))
     }
 
-    fn write_xml_place_file(lua_instance: LuaInstance, path: &Path) -> mlua::Result<()> {
-        let file = BufWriter::new(File::create(&path).map_err(mlua::Error::external)?);
+    fn write_place_file(lua_instance: &LuaInstance, path: &Path) -> mlua::Result<()> {
+        let document_type = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .and_then(|extension| document_type_from_extension(extension, "rbxlx", "rbxl"))
+            .ok_or_else(|| {
+                mlua::Error::external(format!("Invalid place file path {}", path.display()))
+            })?;
+
+        let buffer = Remodel::encode_place(lua_instance, document_type)?;
+        fs::write(path, buffer).map_err(mlua::Error::external)
+    }
 
-        let tree = lua_instance.tree.lock().unwrap();
-        let instance = tree
-            .get_by_ref(lua_instance.id)
-            .ok_or_else(|| mlua::Error::external("Cannot save a destroyed instance."))?;
+    fn write_model_file(lua_instances: &[LuaInstance], path: &Path) -> mlua::Result<()> {
+        let document_type = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .and_then(|extension| document_type_from_extension(extension, "rbxmx", "rbxm"))
+            .ok_or_else(|| {
+                mlua::Error::external(format!("Invalid model file path {}", path.display()))
+            })?;
+
+        let buffer = Remodel::encode_model(lua_instances, document_type)?;
+        fs::write(path, buffer).map_err(mlua::Error::external)
+    }
 
-        if instance.class != "DataModel" {
-            return Err(mlua::Error::external(
-                "Only DataModel instances can be saved as place files.",
-            ));
+    /// Converts a place or model file to the format given by the extension of
+    /// `output`. Places can only be converted to places and models to models.
+    pub fn convert_file(context: &Lua, input: &Path, output: &Path) -> mlua::Result<()> {
+        let input_kind = file_kind(input)?;
+        let output_kind = file_kind(output)?;
+
+        if input_kind != output_kind {
+            return Err(mlua::Error::external(format!(
+                "Cannot convert {} to {}, since one is a place and the other is a model",
+                input.display(),
+                output.display()
+            )));
         }
 
-        rbx_xml::to_writer(file, &tree, instance.children(), xml_encode_options())
-            .map_err(mlua::Error::external)?;
+        if let Some(parent) = output.parent() {
+            fs::create_dir_all(parent).map_err(mlua::Error::external)?;
+        }
+
+        let (instances, result) = match input_kind {
+            FileKind::Place => {
+                let place = Remodel::read_place_file(context, input)?;
+                let result = Remodel::write_place_file(&place, output);
+                (vec![place], result)
+            }
+            FileKind::Model => {
+                let instances = Remodel::read_model_file(context, input)?;
+                let result = Remodel::write_model_file(&instances, output);
+                (instances, result)
+            }
+        };
+
+        // Converting a directory would otherwise keep every file in memory
+        // until the end.
+        for instance in instances {
+            instance.tree.lock().unwrap().destroy(instance.id);
+        }
+
+        result
+    }
+
+    /// Converts every place and model file in the directory `input` and its
+    /// subdirectories to the given format, keeping the same layout in
+    /// `output`. Files that fail to convert are reported, but don't stop the
+    /// rest from being converted.
+    pub fn convert_dir(
+        context: &Lua,
+        input: &Path,
+        output: &Path,
+        document_type: DocumentType,
+    ) -> mlua::Result<()> {
+        let mut failures = 0;
+        Remodel::convert_dir_inner(context, input, output, document_type, &mut failures)?;
+
+        if failures > 0 {
+            return Err(mlua::Error::external(format!(
+                "{} file{} could not be converted",
+                failures,
+                if failures == 1 { "" } else { "s" }
+            )));
+        }
 
         Ok(())
     }
 
-    fn write_binary_place_file(lua_instance: LuaInstance, path: &Path) -> mlua::Result<()> {
-        let file = BufWriter::new(File::create(&path).map_err(mlua::Error::external)?);
+    fn convert_dir_inner(
+        context: &Lua,
+        input: &Path,
+        output: &Path,
+        document_type: DocumentType,
+        failures: &mut usize,
+    ) -> mlua::Result<()> {
+        let mut paths = fs::read_dir(input)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(mlua::Error::external)?;
+        paths.sort();
+
+        for path in paths {
+            let file_name = path.file_name().unwrap();
+
+            if path.is_dir() {
+                Remodel::convert_dir_inner(
+                    context,
+                    &path,
+                    &output.join(file_name),
+                    document_type,
+                    failures,
+                )?;
+                continue;
+            }
+
+            let extension = match (file_kind(&path), document_type) {
+                (Ok(FileKind::Place), DocumentType::Xml) => "rbxlx",
+                (Ok(FileKind::Place), DocumentType::Binary) => "rbxl",
+                (Ok(FileKind::Model), DocumentType::Xml) => "rbxmx",
+                (Ok(FileKind::Model), DocumentType::Binary) => "rbxm",
+                (Err(_), _) => continue,
+            };
+
+            let output_path = output.join(file_name).with_extension(extension);
+
+            log::info!("Converting {} to {}", path.display(), output_path.display());
 
+            if let Err(err) = Remodel::convert_file(context, &path, &output_path) {
+                log::error!("Could not convert {}: {}", path.display(), err);
+                *failures += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_dir_tree(
+        lua_instance: &LuaInstance,
+        path: &Path,
+        model_type: DocumentType,
+    ) -> mlua::Result<()> {
         let tree = lua_instance.tree.lock().unwrap();
-        let instance = tree
-            .get_by_ref(lua_instance.id)
-            .ok_or_else(|| mlua::Error::external("Cannot save a destroyed instance."))?;
+        if tree.get_by_ref(lua_instance.id).is_none() {
+            return Err(mlua::Error::external(
+                "Cannot call remodel.writeDirTree on a destroyed instance.",
+            ));
+        }
 
-        if instance.class != "DataModel" {
+        write_dir_tree(&tree, lua_instance.id, path, model_type)
+            .map_err(|err| mlua::Error::external(format!("{:#}", err)))
+    }
+
+    fn diff<'lua>(
+        context: &'lua Lua,
+        old: &LuaInstance,
+        new: &LuaInstance,
+        format: &str,
+    ) -> mlua::Result<mlua::Value<'lua>> {
+        if !Arc::ptr_eq(&old.tree, &new.tree) {
             return Err(mlua::Error::external(
-                "Only DataModel instances can be saved as place files.",
+                "Cannot diff instances that come from different trees.",
             ));
         }
 
-        rbx_binary::to_writer(file, &tree, instance.children()).map_err(mlua::Error::external)?;
+        let tree = old.tree.lock().unwrap();
+        if tree.get_by_ref(old.id).is_none() || tree.get_by_ref(new.id).is_none() {
+            return Err(mlua::Error::external(
+                "Cannot call remodel.diff on a destroyed instance.",
+            ));
+        }
 
-        Ok(())
+        let diff = TreeDiff::new(&tree, old.id, &tree, new.id);
+
+        match format {
+            "table" => Self::diff_to_lua(context, old, &tree, &diff).map(mlua::Value::Table),
+            "text" => context
+                .create_string(&diff.to_text())
+                .map(mlua::Value::String),
+            "json" => {
+                let json =
+                    serde_json::to_string_pretty(&diff.to_json()).map_err(mlua::Error::external)?;
+                context.create_string(&json).map(mlua::Value::String)
+            }
+            _ => Err(mlua::Error::external(format!(
+                "Invalid diff format '{}', expected 'table', 'text', or 'json'",
+                format
+            ))),
+        }
     }
 
-    fn write_xml_model_file(lua_instance: LuaInstance, path: &Path) -> mlua::Result<()> {
-        let file = BufWriter::new(File::create(&path).map_err(mlua::Error::external)?);
+    fn diff_to_lua<'lua>(
+        context: &'lua Lua,
+        lua_instance: &LuaInstance,
+        tree: &WeakDom,
+        diff: &TreeDiff<'_>,
+    ) -> mlua::Result<mlua::Table<'lua>> {
+        let instance = |id: Ref| LuaInstance::new(Arc::clone(&lua_instance.tree), id);
+        let entry = |value: Option<&Variant>| -> mlua::Result<mlua::Value<'lua>> {
+            let value = match value {
+                Some(value) => value,
+                None => return Ok(mlua::Value::Nil),
+            };
+
+            let lua_value = match value {
+                Variant::Ref(referent) => lua_instance.ref_to_lua(context, tree, *referent)?,
+                _ => property_value_to_lua(context, value),
+            };
+
+            property_entry(context, value, lua_value).map(mlua::Value::Table)
+        };
 
-        let tree = lua_instance.tree.lock().unwrap();
-        let instance = tree
-            .get_by_ref(lua_instance.id)
-            .ok_or_else(|| mlua::Error::external("Cannot save a destroyed instance."))?;
+        let added = context.create_table()?;
+        for (index, &id) in diff.added.iter().enumerate() {
+            let change = context.create_table()?;
+            change.set("path", diff.new_path(id))?;
+            change.set("className", diff.new_instance(id).class.as_str())?;
+            change.set("instance", instance(id))?;
+            added.set(index + 1, change)?;
+        }
 
-        if instance.class == "DataModel" {
+        let removed = context.create_table()?;
+        for (index, &id) in diff.removed.iter().enumerate() {
+            let change = context.create_table()?;
+            change.set("path", diff.old_path(id))?;
+            change.set("className", diff.old_instance(id).class.as_str())?;
+            change.set("instance", instance(id))?;
+            removed.set(index + 1, change)?;
+        }
+
+        let moved = context.create_table()?;
+        for (index, &(old, new)) in diff.moved.iter().enumerate() {
+            let change = context.create_table()?;
+            change.set("from", diff.old_path(old))?;
+            change.set("to", diff.new_path(new))?;
+            change.set("className", diff.new_instance(new).class.as_str())?;
+            change.set("instance", instance(new))?;
+            moved.set(index + 1, change)?;
+        }
+
+        let changed = context.create_table()?;
+        for (index, changed_instance) in diff.changed.iter().enumerate() {
+            let properties = context.create_table()?;
+
+            for property in &changed_instance.properties {
+                let property_change = context.create_table()?;
+                property_change.set("old", entry(property.old)?)?;
+                property_change.set("new", entry(property.new)?)?;
+                property_change.set("diff", property.source_diff())?;
+                properties.set(property.name.as_str(), property_change)?;
+            }
+
+            let change = context.create_table()?;
+            change.set("path", diff.new_path(changed_instance.new))?;
+            change.set(
+                "className",
+                diff.new_instance(changed_instance.new).class.as_str(),
+            )?;
+            change.set("instance", instance(changed_instance.new))?;
+            change.set("properties", properties)?;
+            changed.set(index + 1, change)?;
+        }
+
+        let result = context.create_table()?;
+        result.set("added", added)?;
+        result.set("removed", removed)?;
+        result.set("moved", moved)?;
+        result.set("changed", changed)?;
+
+        Ok(result)
+    }
+
+    fn merge<'lua>(
+        context: &'lua Lua,
+        base: &LuaInstance,
+        ours: &LuaInstance,
+        theirs: &LuaInstance,
+    ) -> mlua::Result<(LuaInstance, mlua::Table<'lua>)> {
+        if !Arc::ptr_eq(&base.tree, &ours.tree) || !Arc::ptr_eq(&base.tree, &theirs.tree) {
             return Err(mlua::Error::external(
-                "DataModel instances must be saved as place files, not model files.",
+                "Cannot merge instances that come from different trees.",
             ));
         }
 
-        rbx_xml::to_writer(file, &tree, &[lua_instance.id], xml_encode_options())
-            .map_err(mlua::Error::external)
-    }
+        let mut tree = base.tree.lock().unwrap();
+        if [base.id, ours.id, theirs.id]
+            .iter()
+            .any(|&id| tree.get_by_ref(id).is_none())
+        {
+            return Err(mlua::Error::external(
+                "Cannot call remodel.merge on a destroyed instance.",
+            ));
+        }
+
+        let master_root_ref = tree.root_ref();
+        let result = merge(&mut tree, base.id, ours.id, theirs.id, master_root_ref);
+
+        let instance = |id: Ref| LuaInstance::new(Arc::clone(&base.tree), id);
+        let entry = |value: &Option<Variant>| -> mlua::Result<mlua::Value<'lua>> {
+            let value = match value {
+                Some(value) => value,
+                None => return Ok(mlua::Value::Nil),
+            };
+
+            let lua_value = match value {
+                Variant::Ref(referent) => base.ref_to_lua(context, &tree, *referent)?,
+                _ => property_value_to_lua(context, value),
+            };
+
+            property_entry(context, value, lua_value).map(mlua::Value::Table)
+        };
+
+        let conflicts = context.create_table()?;
+        for (index, conflict) in result.conflicts.iter().enumerate() {
+            let lua_conflict = context.create_table()?;
+
+            let path = match (conflict.merged, conflict.base) {
+                (Some(merged), _) => instance_path(&tree, result.root, merged),
+                (None, Some(base_id)) => instance_path(&tree, base.id, base_id),
+                (None, None) => String::new(),
+            };
+            lua_conflict.set("path", path)?;
+            lua_conflict.set("instance", conflict.merged.map(instance))?;
+            lua_conflict.set("base", conflict.base.map(instance))?;
+            lua_conflict.set("ours", conflict.ours.map(instance))?;
+            lua_conflict.set("theirs", conflict.theirs.map(instance))?;
+            lua_conflict.set("theirsParent", conflict.theirs_parent.map(instance))?;
+
+            match &conflict.kind {
+                ConflictKind::Property(property) => {
+                    lua_conflict.set("kind", "property")?;
+                    lua_conflict.set("property", property.name.as_str())?;
+                    lua_conflict.set("baseValue", entry(&property.base)?)?;
+                    lua_conflict.set("oursValue", entry(&property.ours)?)?;
+                    lua_conflict.set("theirsValue", entry(&property.theirs)?)?;
+                }
+                ConflictKind::Deleted(side) => {
+                    lua_conflict.set("kind", "deleted")?;
+                    lua_conflict.set(
+                        "deletedIn",
+                        match side {
+                            Side::Ours => "ours",
+                            Side::Theirs => "theirs",
+                        },
+                    )?;
+                }
+                ConflictKind::Moved => lua_conflict.set("kind", "moved")?,
+            }
+
+            conflicts.set(index + 1, lua_conflict)?;
+        }
 
-    fn write_binary_model_file(lua_instance: LuaInstance, path: &Path) -> mlua::Result<()> {
-        let file = BufWriter::new(File::create(&path).map_err(mlua::Error::external)?);
+        Ok((instance(result.root), conflicts))
+    }
 
+    fn encode_place(
+        lua_instance: &LuaInstance,
+        document_type: DocumentType,
+    ) -> mlua::Result<Vec<u8>> {
         let tree = lua_instance.tree.lock().unwrap();
         let instance = tree
             .get_by_ref(lua_instance.id)
             .ok_or_else(|| mlua::Error::external("Cannot save a destroyed instance."))?;
 
-        if instance.class == "DataModel" {
+        if instance.class != "DataModel" {
             return Err(mlua::Error::external(
-                "DataModel instances must be saved as place files, not model files.",
+                "Only DataModel instances can be saved as place files.",
+            ));
+        }
+
+        encode_tree(&tree, instance.children(), document_type)
+    }
+
+    fn encode_model(
+        lua_instances: &[LuaInstance],
+        document_type: DocumentType,
+    ) -> mlua::Result<Vec<u8>> {
+        let first = lua_instances
+            .first()
+            .ok_or_else(|| mlua::Error::external("Cannot save a model with no instances."))?;
+
+        if lua_instances
+            .iter()
+            .any(|lua_instance| !Arc::ptr_eq(&lua_instance.tree, &first.tree))
+        {
+            return Err(mlua::Error::external(
+                "All instances in a model must come from the same tree.",
             ));
         }
 
-        rbx_binary::to_writer(file, &tree, &[lua_instance.id])
-            .map_err(|err| mlua::Error::external(format!("{:?}", err)))
+        let tree = first.tree.lock().unwrap();
+        let ids: Vec<Ref> = lua_instances
+            .iter()
+            .map(|lua_instance| lua_instance.id)
+            .collect();
+
+        for id in &ids {
+            let instance = tree
+                .get_by_ref(*id)
+                .ok_or_else(|| mlua::Error::external("Cannot save a destroyed instance."))?;
+
+            if instance.class == "DataModel" {
+                return Err(mlua::Error::external(
+                    "DataModel instances must be saved as place files, not model files.",
+                ));
+            }
+
+            // Saving an instance along with one of its ancestors would save
+            // the instance twice.
+            let mut current = instance.parent();
+            while let Some(ancestor) = tree.get_by_ref(current) {
+                if ids.contains(&current) {
+                    return Err(mlua::Error::external(format!(
+                        "Cannot save {} in the same model as its ancestor {}.",
+                        instance.name, ancestor.name
+                    )));
+                }
+
+                current = ancestor.parent();
+            }
+        }
+
+        encode_tree(&tree, &ids, document_type)
+    }
+
+    fn decode_place(context: &Lua, buffer: &[u8]) -> mlua::Result<LuaInstance> {
+        let document_type = sniff_type(buffer)
+            .ok_or_else(|| mlua::Error::external("The given string is not a Roblox place"))?;
+        let source_tree = decode_tree(buffer, document_type)?;
+
+        Remodel::import_tree_root(context, source_tree)
+    }
+
+    fn decode_model(context: &Lua, buffer: &[u8]) -> mlua::Result<Vec<LuaInstance>> {
+        let document_type = sniff_type(buffer)
+            .ok_or_else(|| mlua::Error::external("The given string is not a Roblox model"))?;
+        let source_tree = decode_tree(buffer, document_type)?;
+
+        Remodel::import_tree_children(context, source_tree)
     }
 
     fn read_model_asset(context: &Lua, asset_id: u64) -> mlua::Result<Vec<LuaInstance>> {
@@ -450,11 +1000,72 @@ impl Remodel {
         })?;
 
         match instance.properties.get(name) {
+            Some(Variant::Ref(referent)) => lua_instance.ref_to_lua(context, &tree, *referent),
             Some(value) => rbxvalue_to_lua(context, value),
             None => Ok(mlua::Value::Nil),
         }
     }
 
+    fn get_properties<'a>(
+        context: &'a Lua,
+        lua_instance: LuaInstance,
+        exclude_defaults: bool,
+    ) -> mlua::Result<mlua::Table<'a>> {
+        let tree = lua_instance.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(lua_instance.id).ok_or_else(|| {
+            mlua::Error::external("Cannot call remodel.getProperties on a destroyed instance.")
+        })?;
+
+        let properties = context.create_table()?;
+
+        for (name, value) in &instance.properties {
+            if exclude_defaults && find_default_property(&instance.class, name) == Some(value) {
+                continue;
+            }
+
+            let lua_value = match value {
+                Variant::Ref(referent) => lua_instance.ref_to_lua(context, &tree, *referent)?,
+                _ => property_value_to_lua(context, value),
+            };
+
+            properties.set(name.as_str(), property_entry(context, value, lua_value)?)?;
+        }
+
+        Ok(properties)
+    }
+
+    fn get_default_properties<'a>(
+        context: &'a Lua,
+        class_name: &str,
+    ) -> mlua::Result<mlua::Table<'a>> {
+        let database = rbx_reflection_database::get();
+
+        if !database.classes.contains_key(class_name) {
+            return Err(mlua::Error::external(format!(
+                "'{}' is not a valid class name.",
+                class_name
+            )));
+        }
+
+        let properties = context.create_table()?;
+
+        // Subclasses come first, so their defaults take priority over the
+        // ones of their superclasses.
+        for class in class_hierarchy(class_name) {
+            for (name, value) in &class.default_properties {
+                if properties.contains_key(name.as_ref())? {
+                    continue;
+                }
+
+                let lua_value = property_value_to_lua(context, value);
+                properties.set(name.as_ref(), property_entry(context, value, lua_value)?)?;
+            }
+        }
+
+        Ok(properties)
+    }
+
     fn set_raw_property(
         lua_instance: LuaInstance,
         key: String,
@@ -463,11 +1074,18 @@ impl Remodel {
     ) -> mlua::Result<()> {
         let mut tree = lua_instance.tree.lock().unwrap();
 
-        let instance = tree.get_by_ref_mut(lua_instance.id).ok_or_else(|| {
-            mlua::Error::external("Cannot call remodel.setRawProperty on a destroyed instance.")
-        })?;
+        if tree.get_by_ref(lua_instance.id).is_none() {
+            return Err(mlua::Error::external(
+                "Cannot call remodel.setRawProperty on a destroyed instance.",
+            ));
+        }
+
+        let value = match ty {
+            VariantType::Ref => lua_instance.lua_to_ref(&tree, lua_value)?,
+            _ => lua_to_rbxvalue(ty, lua_value)?,
+        };
 
-        let value = lua_to_rbxvalue(ty, lua_value)?;
+        let instance = tree.get_by_ref_mut(lua_instance.id).unwrap();
         instance.properties.insert(key, value);
 
         Ok(())
@@ -493,30 +1111,55 @@ impl UserData for Remodel {
             },
         );
 
-        methods.add_function("readPlaceFile", |context, lua_path: String| {
-            let path = Path::new(&lua_path);
+        methods.add_function(
+            "getProperties",
+            |context, (instance, options): (LuaInstance, Option<mlua::Table<'_>>)| {
+                let exclude_defaults = match options {
+                    Some(options) => options.get::<_, Option<bool>>("excludeDefaults")?,
+                    None => None,
+                };
+
+                Self::get_properties(context, instance, exclude_defaults.unwrap_or(false))
+            },
+        );
 
-            match path.extension().and_then(OsStr::to_str) {
-                Some("rbxlx") => Remodel::read_xml_place_file(context, path),
-                Some("rbxl") => Remodel::read_binary_place_file(context, path),
-                _ => Err(mlua::Error::external(format!(
-                    "Invalid place file path {}",
-                    path.display()
-                ))),
-            }
+        methods.add_function(
+            "diff",
+            |context, (old, new, options): (LuaInstance, LuaInstance, Option<mlua::Table<'_>>)| {
+                let format = match options {
+                    Some(options) => options.get::<_, Option<String>>("format")?,
+                    None => None,
+                };
+
+                Self::diff(context, &old, &new, format.as_deref().unwrap_or("table"))
+            },
+        );
+
+        methods.add_function(
+            "merge",
+            |context, (base, ours, theirs): (LuaInstance, LuaInstance, LuaInstance)| {
+                Self::merge(context, &base, &ours, &theirs)
+            },
+        );
+
+        methods.add_function("getDefaultProperties", |context, class_name: String| {
+            Self::get_default_properties(context, &class_name)
+        });
+
+        methods.add_function("readPlaceFile", |context, lua_path: String| {
+            Remodel::read_place_file(context, Path::new(&lua_path))
         });
 
         methods.add_function("readModelFile", |context, lua_path: String| {
-            let path = Path::new(&lua_path);
+            Remodel::read_model_file(context, Path::new(&lua_path))
+        });
 
-            match path.extension().and_then(OsStr::to_str) {
-                Some("rbxmx") => Remodel::read_xml_model_file(context, path),
-                Some("rbxm") => Remodel::read_binary_model_file(context, path),
-                _ => Err(mlua::Error::external(format!(
-                    "Invalid model file path {}",
-                    path.display()
-                ))),
-            }
+        methods.add_function("readProject", |context, lua_path: String| {
+            Remodel::read_project(context, Path::new(&lua_path))
+        });
+
+        methods.add_function("detectFileType", |_context, lua_path: String| {
+            Remodel::detect_file_type(Path::new(&lua_path))
         });
 
         methods.add_function("readModelAsset", |context, asset_id: String| {
@@ -570,35 +1213,78 @@ impl UserData for Remodel {
         methods.add_function(
             "writePlaceFile",
             |_context, (lua_path, instance): (String, LuaInstance)| {
-                let path = Path::new(&lua_path);
-
-                match path.extension().and_then(OsStr::to_str) {
-                    Some("rbxlx") => Remodel::write_xml_place_file(instance, path),
-                    Some("rbxl") => Remodel::write_binary_place_file(instance, path),
-                    _ => Err(mlua::Error::external(format!(
-                        "Invalid place file path {}",
-                        path.display()
-                    ))),
-                }
+                Remodel::write_place_file(&instance, Path::new(&lua_path))
             },
         );
 
         methods.add_function(
             "writeModelFile",
-            |_context, (lua_path, instance): (String, LuaInstance)| {
-                let path = Path::new(&lua_path);
-
-                match path.extension().and_then(OsStr::to_str) {
-                    Some("rbxmx") => Remodel::write_xml_model_file(instance, path),
-                    Some("rbxm") => Remodel::write_binary_model_file(instance, path),
-                    _ => Err(mlua::Error::external(format!(
-                        "Invalid model file path {}",
-                        path.display()
-                    ))),
-                }
+            |_context, (lua_path, instances): (String, ModelInstances)| {
+                Remodel::write_model_file(&instances.0, Path::new(&lua_path))
             },
         );
 
+        methods.add_function(
+            "writeDirTree",
+            |_context, (instance, lua_path, options): (LuaInstance, String, Option<mlua::Table<'_>>)| {
+                let model_format = match options {
+                    Some(options) => options.get::<_, Option<String>>("modelFormat")?,
+                    None => None,
+                };
+
+                let model_type = match model_format {
+                    Some(format) => document_type_from_extension(&format, "rbxmx", "rbxm")
+                        .ok_or_else(|| {
+                            mlua::Error::external(format!(
+                                "Invalid model format '{}', expected 'rbxm' or 'rbxmx'",
+                                format
+                            ))
+                        })?,
+                    None => DocumentType::Xml,
+                };
+
+                Remodel::write_dir_tree(&instance, Path::new(&lua_path), model_type)
+            },
+        );
+
+        methods.add_function(
+            "encodePlace",
+            |context, (instance, format): (LuaInstance, String)| {
+                let document_type = document_type_from_extension(&format, "rbxlx", "rbxl")
+                    .ok_or_else(|| {
+                        mlua::Error::external(format!(
+                            "Invalid place format '{}', expected 'rbxl' or 'rbxlx'",
+                            format
+                        ))
+                    })?;
+
+                context.create_string(&Remodel::encode_place(&instance, document_type)?)
+            },
+        );
+
+        methods.add_function(
+            "encodeModel",
+            |context, (instances, format): (ModelInstances, String)| {
+                let document_type = document_type_from_extension(&format, "rbxmx", "rbxm")
+                    .ok_or_else(|| {
+                        mlua::Error::external(format!(
+                            "Invalid model format '{}', expected 'rbxm' or 'rbxmx'",
+                            format
+                        ))
+                    })?;
+
+                context.create_string(&Remodel::encode_model(&instances.0, document_type)?)
+            },
+        );
+
+        methods.add_function("decodePlace", |context, buffer: mlua::String<'_>| {
+            Remodel::decode_place(context, buffer.as_bytes())
+        });
+
+        methods.add_function("decodeModel", |context, buffer: mlua::String<'_>| {
+            Remodel::decode_model(context, buffer.as_bytes())
+        });
+
         methods.add_function("readFile", |_context, path: String| {
             fs::read_to_string(path).map_err(mlua::Error::external)
         });
@@ -0,0 +1,187 @@
+//! Replaces Lua's `require` with one that finds modules next to the script
+//! that requires them, no matter which directory Remodel is run from, and
+//! then in the `.remodel` directory of the working directory.
+
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use mlua::{Lua, Table, Value};
+
+const MODULES_KEY: &str = "remodel_modules";
+const LUA_REQUIRE_KEY: &str = "remodel_lua_require";
+
+pub fn inject_require(context: &Lua) -> mlua::Result<()> {
+    let lua_require: Value = context.globals().get("require")?;
+    context.set_named_registry_value(LUA_REQUIRE_KEY, lua_require)?;
+    context.set_named_registry_value(MODULES_KEY, context.create_table()?)?;
+
+    // The modules that are being loaded right now, in the order they were
+    // required, for telling when a module ends up requiring itself.
+    let loading: Rc<RefCell<Vec<LoadingModule>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let require =
+        context.create_function(move |context, name: String| require(context, &name, &loading))?;
+
+    context.globals().set("require", require)
+}
+
+struct LoadingModule {
+    key: PathBuf,
+    path: PathBuf,
+}
+
+fn require<'lua>(
+    context: &'lua Lua,
+    name: &str,
+    loading: &RefCell<Vec<LoadingModule>>,
+) -> mlua::Result<Value<'lua>> {
+    let candidates = module_candidates(&caller_directory(context), name);
+
+    let path = match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => path,
+
+        // Modules that aren't Lua files next to the script, like the ones
+        // from the standard library, are found the usual way.
+        None => {
+            let lua_require: mlua::Function = context.named_registry_value(LUA_REQUIRE_KEY)?;
+
+            return lua_require.call(name).map_err(|err| {
+                let mut tried = candidates
+                    .iter()
+                    .map(|path| format!("\n\tno file '{}'", path.display()))
+                    .collect::<String>();
+
+                // Lua's error lists the places it looked, after a line
+                // naming the module and before the traceback.
+                match &err {
+                    mlua::Error::RuntimeError(message) => {
+                        let searched = message.split("\nstack traceback:").next().unwrap_or("");
+                        for line in searched.lines().skip(1) {
+                            tried.push('\n');
+                            tried.push_str(line);
+                        }
+                    }
+                    _ => tried.push_str(&format!("\n{}", err)),
+                }
+
+                mlua::Error::external(format!("module '{}' not found:{}", name, tried))
+            });
+        }
+    };
+
+    // The same file can be required with different names, like from scripts
+    // in different directories, but is only loaded once.
+    let key = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+    let key_string = key.to_string_lossy().into_owned();
+
+    let modules: Table = context.named_registry_value(MODULES_KEY)?;
+    if let Some(module) = modules.get::<_, Option<Value>>(key_string.as_str())? {
+        return Ok(module);
+    }
+
+    if let Some(start) = loading.borrow().iter().position(|module| module.key == key) {
+        let cycle = loading.borrow()[start..]
+            .iter()
+            .map(|module| module.path.as_path())
+            .chain(std::iter::once(path.as_path()))
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+
+        return Err(mlua::Error::external(format!(
+            "Cyclic require of module '{}': {}",
+            name, cycle
+        )));
+    }
+
+    let contents = fs::read_to_string(path).map_err(|err| {
+        mlua::Error::external(format!("Could not read {}: {}", path.display(), err))
+    })?;
+
+    let chunk = context
+        .load(&contents)
+        .set_name(&format!("@{}", path.display()))?;
+
+    loading.borrow_mut().push(LoadingModule {
+        key,
+        path: path.clone(),
+    });
+    let result = chunk.call::<_, Value>(name);
+    loading.borrow_mut().pop();
+
+    // Like in Lua, modules that don't return anything are still only loaded
+    // once.
+    let module = match result? {
+        Value::Nil => Value::Boolean(true),
+        module => module,
+    };
+
+    modules.set(key_string, module.clone())?;
+    Ok(module)
+}
+
+/// Finds the directory of the Lua script that called `require`. Scripts that
+/// weren't loaded from a file, like ones from stdin, are treated as being in
+/// the working directory.
+fn caller_directory(context: &Lua) -> PathBuf {
+    let mut level = 1;
+
+    while let Some(debug) = context.inspect_stack(level) {
+        let source = debug.source();
+
+        // Skip over functions like `pcall` that called `require` on behalf
+        // of a script.
+        if source.what == Some(b"C") {
+            level += 1;
+            continue;
+        }
+
+        if let Some(path) = source.source.and_then(|source| source.strip_prefix(b"@")) {
+            let path = Path::new(std::str::from_utf8(path).unwrap_or(""));
+            return path.parent().map(Path::to_path_buf).unwrap_or_default();
+        }
+
+        break;
+    }
+
+    PathBuf::new()
+}
+
+/// Lists the files that a module name could refer to, in the order they're
+/// tried. Names can either separate directories with dots, like
+/// `lib.strings`, or be relative paths, like `../lib/strings.lua`.
+fn module_candidates(directory: &Path, name: &str) -> Vec<PathBuf> {
+    let relative = if name.contains('/') || name.contains('\\') {
+        PathBuf::from(name.strip_suffix(".lua").unwrap_or(name))
+    } else {
+        name.split('.').collect()
+    };
+
+    let mut directories = vec![directory.to_path_buf()];
+    let remodel_directory = PathBuf::from(".remodel");
+    if !same_directory(directory, &remodel_directory) {
+        directories.push(remodel_directory);
+    }
+
+    directories
+        .into_iter()
+        .flat_map(|directory| {
+            let module = directory.join(&relative);
+            let mut file = module.clone().into_os_string();
+            file.push(".lua");
+
+            vec![PathBuf::from(file), module.join("init.lua")]
+        })
+        .collect()
+}
+
+fn same_directory(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
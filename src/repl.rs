@@ -0,0 +1,213 @@
+//! An interactive prompt for running Lua one chunk at a time, keeping the
+//! same Lua state between chunks.
+
+use std::path::PathBuf;
+
+use mlua::{Lua, MultiValue};
+use rustyline::{error::ReadlineError, Editor};
+
+use crate::roblox_api::LuaInstance;
+
+const HISTORY_FILE_NAME: &str = ".remodel_history";
+
+/// Reads chunks from the user and runs them until they quit, printing the
+/// values that each chunk returns.
+pub fn run_repl(lua: &Lua) -> anyhow::Result<()> {
+    let mut editor = Editor::<()>::new();
+    let history_path = history_path();
+
+    if let Some(path) = &history_path {
+        // There's no history yet the first time the REPL is used.
+        let _ = editor.load_history(path);
+    }
+
+    println!(
+        "Remodel {} REPL. Press Ctrl-D to exit.",
+        env!("CARGO_PKG_VERSION")
+    );
+
+    let mut buffer = String::new();
+
+    loop {
+        let prompt = if buffer.is_empty() { "> " } else { ">> " };
+
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+
+            // Ctrl-C throws away the chunk being typed, like in a shell.
+            Err(ReadlineError::Interrupted) => {
+                buffer.clear();
+                continue;
+            }
+            Err(ReadlineError::Eof) => break,
+            Err(err) => return Err(err.into()),
+        };
+
+        if !buffer.is_empty() {
+            buffer.push('\n');
+        }
+        buffer.push_str(&line);
+
+        if buffer.trim().is_empty() {
+            buffer.clear();
+            continue;
+        }
+
+        let function = match load_chunk(lua, &buffer) {
+            Ok(function) => function,
+            Err(mlua::Error::SyntaxError {
+                incomplete_input: true,
+                ..
+            }) => continue,
+            Err(err) => {
+                editor.add_history_entry(buffer.as_str());
+                eprintln!("{}", err);
+                buffer.clear();
+                continue;
+            }
+        };
+
+        editor.add_history_entry(buffer.as_str());
+        buffer.clear();
+
+        match function.call::<_, MultiValue>(()) {
+            Ok(values) => {
+                for value in values {
+                    // Printing can fail too, like when a script replaces
+                    // `tostring`, which shouldn't end the session.
+                    match format_value(lua, &value, 0, &mut Vec::new()) {
+                        Ok(formatted) => println!("{}", formatted),
+                        Err(err) => eprintln!("{}", err),
+                    }
+                }
+            }
+            Err(err) => eprintln!("{}", err),
+        }
+    }
+
+    if let Some(path) = &history_path {
+        if let Err(err) = editor.save_history(path) {
+            log::warn!("Could not save REPL history: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+fn history_path() -> Option<PathBuf> {
+    dirs_next::home_dir().map(|home| home.join(HISTORY_FILE_NAME))
+}
+
+/// Loads a chunk as an expression if it is one, so that typing `workspace`
+/// prints it, and as a list of statements otherwise.
+fn load_chunk<'lua>(lua: &'lua Lua, source: &str) -> mlua::Result<mlua::Function<'lua>> {
+    let expression = lua
+        .load(&format!("return {}", source))
+        .set_name("repl")?
+        .into_function();
+
+    match expression {
+        Ok(function) => Ok(function),
+        Err(_) => lua.load(source).set_name("repl")?.into_function(),
+    }
+}
+
+/// Describes a value for the user. Instances are shown with their full name
+/// and class, and tables are expanded.
+fn format_value<'lua>(
+    lua: &'lua Lua,
+    value: &mlua::Value<'lua>,
+    indent: usize,
+    seen: &mut Vec<mlua::Table<'lua>>,
+) -> mlua::Result<String> {
+    match value {
+        mlua::Value::String(value) => Ok(format!("{:?}", value.to_string_lossy())),
+        mlua::Value::UserData(user_data) if user_data.is::<LuaInstance>() => {
+            let instance = user_data.borrow::<LuaInstance>()?;
+
+            let class_name = {
+                let tree = instance.tree.lock().unwrap();
+                tree.get_by_ref(instance.id)
+                    .map(|instance| instance.class.clone())
+            };
+
+            match class_name {
+                Some(class_name) => Ok(format!("{} ({})", instance.get_full_name()?, class_name)),
+                None => Ok("<destroyed Instance>".to_owned()),
+            }
+        }
+        mlua::Value::Table(table) => format_table(lua, table, indent, seen),
+        _ => lua
+            .globals()
+            .get::<_, mlua::Function>("tostring")?
+            .call::<_, String>(value.clone()),
+    }
+}
+
+fn format_table<'lua>(
+    lua: &'lua Lua,
+    table: &mlua::Table<'lua>,
+    indent: usize,
+    seen: &mut Vec<mlua::Table<'lua>>,
+) -> mlua::Result<String> {
+    if seen.contains(table) {
+        return Ok("<cycle>".to_owned());
+    }
+
+    let length = table.raw_len();
+    let mut entries = Vec::new();
+    let mut keyed_entries = Vec::new();
+
+    seen.push(table.clone());
+
+    for pair in table.clone().pairs::<mlua::Value, mlua::Value>() {
+        let (key, value) = pair?;
+        let formatted = format_value(lua, &value, indent + 1, seen)?;
+
+        match key {
+            mlua::Value::Integer(index) if index >= 1 && index <= length => {
+                entries.push((index, formatted));
+            }
+            mlua::Value::String(name) if is_identifier(name.to_str().unwrap_or("")) => {
+                keyed_entries.push(format!("{} = {}", name.to_str()?, formatted));
+            }
+            _ => {
+                let key = format_value(lua, &key, indent + 1, seen)?;
+                keyed_entries.push(format!("[{}] = {}", key, formatted));
+            }
+        }
+    }
+
+    seen.pop();
+
+    if entries.is_empty() && keyed_entries.is_empty() {
+        return Ok("{}".to_owned());
+    }
+
+    // Keys come out of `pairs` in no particular order.
+    entries.sort_by_key(|(index, _)| *index);
+    keyed_entries.sort();
+
+    let inner_indent = "  ".repeat(indent + 1);
+    let mut output = "{\n".to_owned();
+
+    for entry in entries
+        .into_iter()
+        .map(|(_, entry)| entry)
+        .chain(keyed_entries)
+    {
+        output.push_str(&format!("{}{},\n", inner_indent, entry));
+    }
+
+    output.push_str(&"  ".repeat(indent));
+    output.push('}');
+
+    Ok(output)
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
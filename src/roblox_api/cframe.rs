@@ -1,71 +1,127 @@
-use mlua::{UserData, UserDataMethods, Value as LuaValue};
-use rbx_dom_weak::types::{CFrame, Matrix3, Vector3};
+use mlua::{UserData, UserDataMethods, Value as LuaValue, Variadic};
+use rbx_dom_weak::types::Vector3;
 
 use crate::value::{CFrameValue, Vector3Value};
 
-pub struct CFrameUserData;
+use super::try_into_f32;
 
-impl CFrameUserData {
-    fn from_position(x: f32, y: f32, z: f32) -> CFrameValue {
-        CFrameValue::new(CFrame::new(
-            Vector3::new(x as f32, y as f32, z as f32),
-            // TODO: replace with `Matrix3::identity()` once
-            // a version higher than 0.3.0 of rbx_types ships
-            Matrix3::new(
-                Vector3::new(1.0, 0.0, 0.0),
-                Vector3::new(0.0, 1.0, 0.0),
-                Vector3::new(0.0, 0.0, 1.0),
-            ),
-        ))
-    }
-}
+pub struct CFrameUserData;
 
-fn try_into_f32(value: LuaValue<'_>) -> Option<f32> {
+fn try_into_vector3(value: &LuaValue<'_>) -> Option<Vector3> {
     match value {
-        LuaValue::Number(num) => Some(num as f32),
-        LuaValue::Integer(int) => Some(int as f32),
+        LuaValue::UserData(user_data) => user_data
+            .borrow::<Vector3Value>()
+            .ok()
+            .map(|vector| vector.inner()),
         _ => None,
     }
 }
 
 impl UserData for CFrameUserData {
     fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
-        methods.add_function(
-            "new",
-            |_context,
-             arguments: (
-                Option<LuaValue<'_>>,
-                Option<LuaValue<'_>>,
-                Option<LuaValue<'_>>,
-            )| {
-                match arguments {
-                    (None, None, None) => return Ok(Self::from_position(0.0, 0.0, 0.0)),
-                    (Some(LuaValue::UserData(user_data)), None, None) => {
-                        let position = &*user_data.borrow::<Vector3Value>()?;
-                        return Ok(CFrameValue::new(CFrame::new(
-                            position.inner(),
-                            // TODO: replace with `rbx_dom_weak::types::Matrix3::identity()` once
-                            // a version higher than 0.3.0 of rbx_types ships
-                            Matrix3::new(
-                                Vector3::new(1.0, 0.0, 0.0),
-                                Vector3::new(0.0, 1.0, 0.0),
-                                Vector3::new(0.0, 0.0, 1.0),
-                            ),
-                        )));
+        methods.add_function("new", |_context, arguments: Variadic<LuaValue<'_>>| {
+            match arguments.as_slice() {
+                [] => return Ok(CFrameValue::from_position(Vector3::new(0.0, 0.0, 0.0))),
+                [position] => {
+                    if let Some(position) = try_into_vector3(position) {
+                        return Ok(CFrameValue::from_position(position));
+                    }
+                }
+                [position, target] => {
+                    if let (Some(position), Some(target)) =
+                        (try_into_vector3(position), try_into_vector3(target))
+                    {
+                        return Ok(CFrameValue::look_at(
+                            position,
+                            target,
+                            Vector3::new(0.0, 1.0, 0.0),
+                        ));
                     }
-                    _ => {}
-                };
+                }
+                _ => {}
+            };
 
-                let x = arguments.0.and_then(try_into_f32);
-                let y = arguments.1.and_then(try_into_f32);
-                let z = arguments.2.and_then(try_into_f32);
+            let numbers = arguments
+                .iter()
+                .cloned()
+                .map(try_into_f32)
+                .collect::<Option<Vec<_>>>();
 
-                match (x, y, z) {
-                    (Some(x), Some(y), Some(z)) => Ok(Self::from_position(x, y, z)),
-                    _ => Err(mlua::Error::external(
-                        "invalid argument #1 to 'new' (Vector3 expected)",
-                    )),
+            match numbers.as_deref() {
+                Some(&[x, y, z]) => Ok(CFrameValue::from_position(Vector3::new(x, y, z))),
+                Some(&[x, y, z, qx, qy, qz, qw]) => Ok(CFrameValue::from_quaternion(
+                    Vector3::new(x, y, z),
+                    [qx, qy, qz, qw],
+                )),
+                Some(&[x, y, z, r00, r01, r02, r10, r11, r12, r20, r21, r22]) => {
+                    Ok(CFrameValue::from_components([
+                        x, y, z, r00, r01, r02, r10, r11, r12, r20, r21, r22,
+                    ]))
                 }
+                _ => Err(mlua::Error::external(
+                    "invalid argument #1 to 'new' (Vector3 expected)",
+                )),
+            }
+        });
+
+        methods.add_function("Angles", |_context, (rx, ry, rz): (f32, f32, f32)| {
+            Ok(CFrameValue::from_euler_angles_xyz(rx, ry, rz))
+        });
+
+        methods.add_function(
+            "fromEulerAnglesXYZ",
+            |_context, (rx, ry, rz): (f32, f32, f32)| {
+                Ok(CFrameValue::from_euler_angles_xyz(rx, ry, rz))
+            },
+        );
+
+        methods.add_function(
+            "fromEulerAnglesYXZ",
+            |_context, (rx, ry, rz): (f32, f32, f32)| {
+                Ok(CFrameValue::from_euler_angles_yxz(rx, ry, rz))
+            },
+        );
+
+        methods.add_function(
+            "fromOrientation",
+            |_context, (rx, ry, rz): (f32, f32, f32)| {
+                Ok(CFrameValue::from_euler_angles_yxz(rx, ry, rz))
+            },
+        );
+
+        methods.add_function(
+            "fromAxisAngle",
+            |_context, (axis, angle): (Vector3Value, f32)| {
+                Ok(CFrameValue::from_axis_angle(axis.inner(), angle))
+            },
+        );
+
+        methods.add_function(
+            "lookAt",
+            |_context, (position, target, up): (Vector3Value, Vector3Value, Option<Vector3Value>)| {
+                let up = up
+                    .map(|up| up.inner())
+                    .unwrap_or_else(|| Vector3::new(0.0, 1.0, 0.0));
+
+                Ok(CFrameValue::look_at(position.inner(), target.inner(), up))
+            },
+        );
+
+        methods.add_function(
+            "fromMatrix",
+            |_context,
+             (position, right, up, back): (
+                Vector3Value,
+                Vector3Value,
+                Vector3Value,
+                Option<Vector3Value>,
+            )| {
+                Ok(CFrameValue::from_matrix(
+                    position.inner(),
+                    right.inner(),
+                    up.inner(),
+                    back.map(|back| back.inner()),
+                ))
             },
         );
     }
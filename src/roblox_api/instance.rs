@@ -1,12 +1,27 @@
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, VecDeque},
     iter::FromIterator,
     sync::{Arc, Mutex},
 };
 
 use mlua::{FromLua, Lua, MetaMethod, ToLua, UserData, UserDataMethods};
-use rbx_dom_weak::{types::Ref, InstanceBuilder, WeakDom};
-use rbx_reflection::ClassTag;
+use rbx_dom_weak::{
+    types::{Attributes, Ref, Tags, Variant, VariantType},
+    InstanceBuilder, WeakDom,
+};
+use rbx_reflection::{
+    ClassTag, DataType, PropertyDescriptor, PropertyKind, PropertySerialization, Scriptability,
+};
+
+use crate::{
+    reflection::{
+        find_canonical_property_descriptor, find_default_property, find_property_descriptor, is_a,
+    },
+    value::{
+        attribute_to_lua, color3uint8_to_color3, enum_to_lua, lua_to_attribute, lua_to_enum,
+        lua_to_rbxvalue, rbxvalue_to_lua, BrickColorValue,
+    },
+};
 
 #[derive(Clone)]
 pub struct LuaInstance {
@@ -19,6 +34,67 @@ impl LuaInstance {
         LuaInstance { tree, id }
     }
 
+    /// Turns a referent stored on an instance in this instance's tree into a
+    /// Lua value. Referents that don't point to a live instance become nil.
+    pub fn ref_to_lua<'lua>(
+        &self,
+        context: &'lua Lua,
+        tree: &WeakDom,
+        referent: Ref,
+    ) -> mlua::Result<mlua::Value<'lua>> {
+        if referent.is_some() && tree.get_by_ref(referent).is_some() {
+            LuaInstance::new(Arc::clone(&self.tree), referent).to_lua(context)
+        } else {
+            Ok(mlua::Value::Nil)
+        }
+    }
+
+    /// Turns an Instance or nil into a referent that can be stored on an
+    /// instance in this instance's tree.
+    pub fn lua_to_ref(&self, tree: &WeakDom, value: mlua::Value<'_>) -> mlua::Result<Variant> {
+        match value {
+            mlua::Value::Nil => Ok(Variant::Ref(Ref::none())),
+            mlua::Value::UserData(ref user_data) if user_data.is::<LuaInstance>() => {
+                let target = user_data.borrow::<LuaInstance>()?;
+
+                if !Arc::ptr_eq(&self.tree, &target.tree) {
+                    return Err(mlua::Error::external(
+                        "Cannot reference an instance from a different tree",
+                    ));
+                }
+
+                if tree.get_by_ref(target.id).is_none() {
+                    return Err(mlua::Error::external(
+                        "Cannot reference a destroyed instance",
+                    ));
+                }
+
+                Ok(Variant::Ref(target.id))
+            }
+            _ => Err(mlua::Error::external("Expected an Instance or nil")),
+        }
+    }
+
+    fn add_tag(&self, tag: &str) -> mlua::Result<()> {
+        let mut tree = self.tree.lock().unwrap();
+
+        let instance = tree
+            .get_by_ref_mut(self.id)
+            .ok_or_else(|| mlua::Error::external("Cannot call AddTag() on a destroyed instance"))?;
+
+        let mut tags = read_tags(instance)?;
+
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag);
+        }
+
+        instance
+            .properties
+            .insert("Tags".to_owned(), Variant::Tags(tags));
+
+        Ok(())
+    }
+
     fn clear_all_children(&self) -> mlua::Result<()> {
         let mut tree = self.tree.lock().unwrap();
 
@@ -35,7 +111,7 @@ impl LuaInstance {
         Ok(())
     }
 
-    fn clone_instance(&self) -> mlua::Result<LuaInstance> {
+    fn clone_instance(&self) -> mlua::Result<Option<LuaInstance>> {
         let mut tree = self.tree.lock().unwrap();
 
         if tree.get_by_ref(self.id).is_none() {
@@ -45,16 +121,47 @@ impl LuaInstance {
         }
 
         let root_id = tree.root_ref();
-        let new_id = Self::clone_kernel(&mut tree, self.id, root_id);
+        let mut referents = HashMap::new();
+        let new_id = match Self::clone_kernel(&mut tree, self.id, root_id, &mut referents) {
+            Some(new_id) => new_id,
+            None => return Ok(None),
+        };
+
+        // References between instances inside the cloned subtree should point
+        // at the copies. References to anything else are left alone.
+        for copy_id in referents.values() {
+            let copy = tree.get_by_ref_mut(*copy_id).unwrap();
+
+            for value in copy.properties.values_mut() {
+                if let Variant::Ref(referent) = value {
+                    if let Some(new_referent) = referents.get(referent) {
+                        *referent = *new_referent;
+                    }
+                }
+            }
+        }
 
-        Ok(LuaInstance {
+        Ok(Some(LuaInstance {
             tree: Arc::clone(&self.tree),
             id: new_id,
-        })
+        }))
     }
 
-    fn clone_kernel(tree: &mut WeakDom, id: Ref, parent_id: Ref) -> Ref {
+    /// Copies the given instance and its descendants into `parent_id`,
+    /// recording which copy was made from which original in `referents`.
+    /// Like in Roblox, instances with `Archivable` set to false are skipped.
+    fn clone_kernel(
+        tree: &mut WeakDom,
+        id: Ref,
+        parent_id: Ref,
+        referents: &mut HashMap<Ref, Ref>,
+    ) -> Option<Ref> {
         let instance = tree.get_by_ref(id).unwrap();
+
+        if let Some(Variant::Bool(false)) = instance.properties.get("Archivable") {
+            return None;
+        }
+
         let builder = InstanceBuilder::new(&instance.class)
             .with_name(&instance.name)
             .with_properties(
@@ -66,12 +173,13 @@ impl LuaInstance {
         let children = instance.children().to_vec();
 
         let new_id = tree.insert(parent_id, builder);
+        referents.insert(id, new_id);
 
         for child_id in children {
-            Self::clone_kernel(tree, child_id, new_id);
+            Self::clone_kernel(tree, child_id, new_id, referents);
         }
 
-        new_id
+        Some(new_id)
     }
 
     fn destroy(&self) -> mlua::Result<()> {
@@ -88,53 +196,185 @@ impl LuaInstance {
         Ok(())
     }
 
-    fn find_first_child(&self, name: &str) -> mlua::Result<Option<LuaInstance>> {
+    /// Finds the first ancestor of this instance that matches the predicate,
+    /// stopping before the root of the tree.
+    fn find_first_ancestor_matching(
+        &self,
+        method_name: &str,
+        predicate: impl Fn(&rbx_dom_weak::Instance) -> bool,
+    ) -> mlua::Result<Option<LuaInstance>> {
         let tree = self.tree.lock().unwrap();
 
         let instance = tree.get_by_ref(self.id).ok_or_else(|| {
-            mlua::Error::external("Cannot call FindFirstChild() on a destroyed instance")
+            mlua::Error::external(format!(
+                "Cannot call {}() on a destroyed instance",
+                method_name
+            ))
         })?;
 
-        let child = instance
-            .children()
-            .iter()
-            .copied()
-            .find(|id| {
-                if let Some(child_instance) = tree.get_by_ref(*id) {
-                    return child_instance.name == name;
+        let mut current = instance.parent();
+
+        while current.is_some() && current != tree.root_ref() {
+            let ancestor = tree
+                .get_by_ref(current)
+                .expect("received invalid parent in tree when walking ancestors");
+
+            if predicate(ancestor) {
+                return Ok(Some(LuaInstance::new(Arc::clone(&self.tree), current)));
+            }
+
+            current = ancestor.parent();
+        }
+
+        Ok(None)
+    }
+
+    /// Finds the first child of this instance that matches the predicate. If
+    /// `recursive` is set, all descendants are searched in the same order as
+    /// `GetDescendants()` returns them.
+    fn find_first_child_matching(
+        &self,
+        method_name: &str,
+        recursive: bool,
+        predicate: impl Fn(&rbx_dom_weak::Instance) -> bool,
+    ) -> mlua::Result<Option<LuaInstance>> {
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(self.id).ok_or_else(|| {
+            mlua::Error::external(format!(
+                "Cannot call {}() on a destroyed instance",
+                method_name
+            ))
+        })?;
+
+        let mut stack = VecDeque::from_iter(instance.children());
+
+        while let Some(current) = stack.pop_front() {
+            let current_instance = tree
+                .get_by_ref(*current)
+                .expect("received invalid child in tree when recursing through descendants");
+
+            if predicate(current_instance) {
+                return Ok(Some(LuaInstance::new(Arc::clone(&self.tree), *current)));
+            }
+
+            if recursive {
+                for child in current_instance.children().iter().rev() {
+                    stack.push_front(child);
                 }
+            }
+        }
 
-                false
-            })
-            .map(|id| LuaInstance::new(Arc::clone(&self.tree), id));
+        Ok(None)
+    }
 
-        Ok(child)
+    fn find_first_ancestor(&self, name: &str) -> mlua::Result<Option<LuaInstance>> {
+        self.find_first_ancestor_matching("FindFirstAncestor", |ancestor| ancestor.name == name)
+    }
+
+    fn find_first_ancestor_of_class(&self, class_name: &str) -> mlua::Result<Option<LuaInstance>> {
+        self.find_first_ancestor_matching("FindFirstAncestorOfClass", |ancestor| {
+            ancestor.class == class_name
+        })
+    }
+
+    fn find_first_ancestor_which_is_a(
+        &self,
+        class_name: &str,
+    ) -> mlua::Result<Option<LuaInstance>> {
+        self.find_first_ancestor_matching("FindFirstAncestorWhichIsA", |ancestor| {
+            is_a(&ancestor.class, class_name)
+        })
+    }
+
+    fn find_first_child(&self, name: &str, recursive: bool) -> mlua::Result<Option<LuaInstance>> {
+        self.find_first_child_matching("FindFirstChild", recursive, |child| child.name == name)
     }
 
     fn find_first_child_of_class(&self, class_name: &str) -> mlua::Result<Option<LuaInstance>> {
+        self.find_first_child_matching("FindFirstChildOfClass", false, |child| {
+            child.class == class_name
+        })
+    }
+
+    fn find_first_child_which_is_a(
+        &self,
+        class_name: &str,
+        recursive: bool,
+    ) -> mlua::Result<Option<LuaInstance>> {
+        self.find_first_child_matching("FindFirstChildWhichIsA", recursive, |child| {
+            is_a(&child.class, class_name)
+        })
+    }
+
+    fn get_attribute<'lua>(
+        &self,
+        context: &'lua Lua,
+        name: &str,
+    ) -> mlua::Result<mlua::Value<'lua>> {
         let tree = self.tree.lock().unwrap();
 
         let instance = tree.get_by_ref(self.id).ok_or_else(|| {
-            mlua::Error::external("Cannot call FindFirstChildOfClass() on a destroyed instance")
+            mlua::Error::external("Cannot call GetAttribute() on a destroyed instance")
         })?;
 
-        let child = instance
-            .children()
-            .iter()
-            .copied()
-            .find(|id| {
-                if let Some(child_instance) = tree.get_by_ref(*id) {
-                    return child_instance.class == class_name;
-                }
+        match read_attributes(instance)?.get(name) {
+            Some(value) => attribute_to_lua(context, value),
+            None => Ok(mlua::Value::Nil),
+        }
+    }
+
+    fn get_attributes<'lua>(&self, context: &'lua Lua) -> mlua::Result<mlua::Table<'lua>> {
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(self.id).ok_or_else(|| {
+            mlua::Error::external("Cannot call GetAttributes() on a destroyed instance")
+        })?;
+
+        let table = context.create_table()?;
+
+        for (name, value) in read_attributes(instance)?.iter() {
+            table.set(name.as_str(), attribute_to_lua(context, value)?)?;
+        }
+
+        Ok(table)
+    }
+
+    fn set_attribute(&self, name: &str, value: mlua::Value<'_>) -> mlua::Result<()> {
+        let mut tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref_mut(self.id).ok_or_else(|| {
+            mlua::Error::external("Cannot call SetAttribute() on a destroyed instance")
+        })?;
+
+        validate_attribute_name(name)?;
+
+        let mut attributes = read_attributes(instance)?;
+
+        match value {
+            mlua::Value::Nil => {
+                attributes.remove(name);
+            }
+            value => {
+                let value = lua_to_attribute(value).map_err(|err| {
+                    mlua::Error::external(format!("Invalid value for attribute {}: {}", name, err))
+                })?;
+
+                attributes.insert(name.to_owned(), value);
+            }
+        }
 
-                false
-            })
-            .map(|id| LuaInstance::new(Arc::clone(&self.tree), id));
+        // Attributes are always written back in their decoded form so that
+        // rbx_binary and rbx_xml can serialize them.
+        instance.properties.remove("AttributesSerialize");
+        instance
+            .properties
+            .insert("Attributes".to_owned(), Variant::Attributes(attributes));
 
-        Ok(child)
+        Ok(())
     }
 
-    fn get_full_name(&self) -> mlua::Result<String> {
+    pub fn get_full_name(&self) -> mlua::Result<String> {
         let tree = self.tree.lock().unwrap();
 
         let instance = tree.get_by_ref(self.id).ok_or_else(|| {
@@ -164,7 +404,7 @@ impl LuaInstance {
         })?;
 
         let mut descendants = Vec::new();
-        let mut stack = VecDeque::from_iter(instance.children().into_iter());
+        let mut stack = VecDeque::from_iter(instance.children());
 
         while let Some(current) = stack.pop_front() {
             descendants.push(LuaInstance::new(Arc::clone(&self.tree), *current));
@@ -251,6 +491,146 @@ impl LuaInstance {
         }
     }
 
+    fn get_tagged(&self, tag: &str) -> mlua::Result<Vec<LuaInstance>> {
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(self.id).ok_or_else(|| {
+            mlua::Error::external("Cannot call GetTagged() on a destroyed instance")
+        })?;
+
+        // Like GetService(), this is defined on all instances because Remodel
+        // has no way to give methods to specific classes.
+        if instance.class != "CollectionService" {
+            return Err(mlua::Error::external(
+                "Cannot call GetTagged() on an instance that is not a CollectionService",
+            ));
+        }
+
+        // CollectionService only knows about instances in its own DataModel.
+        let data_model = tree
+            .get_by_ref(instance.parent())
+            .filter(|parent| parent.class == "DataModel")
+            .ok_or_else(|| {
+                mlua::Error::external(
+                    "Cannot call GetTagged() on a CollectionService outside of a DataModel",
+                )
+            })?;
+
+        let mut tagged = Vec::new();
+        let mut stack = VecDeque::from_iter(data_model.children());
+
+        while let Some(current) = stack.pop_front() {
+            let current_instance = tree
+                .get_by_ref(*current)
+                .expect("received invalid child in tree when recursing through descendants");
+
+            if read_tags(current_instance)?
+                .iter()
+                .any(|existing| existing == tag)
+            {
+                tagged.push(LuaInstance::new(Arc::clone(&self.tree), *current));
+            }
+
+            for child in current_instance.children().iter().rev() {
+                stack.push_front(child);
+            }
+        }
+
+        Ok(tagged)
+    }
+
+    fn get_tags(&self) -> mlua::Result<Vec<String>> {
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(self.id).ok_or_else(|| {
+            mlua::Error::external("Cannot call GetTags() on a destroyed instance")
+        })?;
+
+        Ok(read_tags(instance)?.iter().map(str::to_owned).collect())
+    }
+
+    fn has_tag(&self, tag: &str) -> mlua::Result<bool> {
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree
+            .get_by_ref(self.id)
+            .ok_or_else(|| mlua::Error::external("Cannot call HasTag() on a destroyed instance"))?;
+
+        Ok(read_tags(instance)?.iter().any(|existing| existing == tag))
+    }
+
+    fn is_a(&self, class_name: &str) -> mlua::Result<bool> {
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree
+            .get_by_ref(self.id)
+            .ok_or_else(|| mlua::Error::external("Cannot call IsA() on a destroyed instance"))?;
+
+        Ok(is_a(&instance.class, class_name))
+    }
+
+    /// Tells whether `ancestor` is a strict ancestor of `descendant`. Both
+    /// must be in this instance's tree.
+    fn is_ancestor(tree: &WeakDom, ancestor: Ref, descendant: Ref) -> bool {
+        let mut current = tree
+            .get_by_ref(descendant)
+            .map(|instance| instance.parent());
+
+        while let Some(id) = current.filter(|id| id.is_some()) {
+            if id == ancestor {
+                return true;
+            }
+
+            current = tree.get_by_ref(id).map(|instance| instance.parent());
+        }
+
+        false
+    }
+
+    fn is_ancestor_of(&self, other: &LuaInstance) -> mlua::Result<bool> {
+        let tree = self.tree.lock().unwrap();
+
+        if tree.get_by_ref(self.id).is_none() {
+            return Err(mlua::Error::external(
+                "Cannot call IsAncestorOf() on a destroyed instance",
+            ));
+        }
+
+        Ok(Arc::ptr_eq(&self.tree, &other.tree) && Self::is_ancestor(&tree, self.id, other.id))
+    }
+
+    fn is_descendant_of(&self, other: &LuaInstance) -> mlua::Result<bool> {
+        let tree = self.tree.lock().unwrap();
+
+        if tree.get_by_ref(self.id).is_none() {
+            return Err(mlua::Error::external(
+                "Cannot call IsDescendantOf() on a destroyed instance",
+            ));
+        }
+
+        Ok(Arc::ptr_eq(&self.tree, &other.tree) && Self::is_ancestor(&tree, other.id, self.id))
+    }
+
+    fn remove_tag(&self, tag: &str) -> mlua::Result<()> {
+        let mut tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref_mut(self.id).ok_or_else(|| {
+            mlua::Error::external("Cannot call RemoveTag() on a destroyed instance")
+        })?;
+
+        let tags: Vec<String> = read_tags(instance)?
+            .iter()
+            .filter(|existing| *existing != tag)
+            .map(str::to_owned)
+            .collect();
+
+        instance
+            .properties
+            .insert("Tags".to_owned(), Variant::Tags(tags.into()));
+
+        Ok(())
+    }
+
     fn get_class_name<'lua>(&self, context: &'lua Lua) -> mlua::Result<mlua::Value<'lua>> {
         let tree = self.tree.lock().unwrap();
 
@@ -325,11 +705,124 @@ impl LuaInstance {
 
     fn get_property<'lua>(
         &self,
-        _context: &'lua Lua,
-        _name: &str,
+        context: &'lua Lua,
+        name: &str,
     ) -> mlua::Result<Option<mlua::Value<'lua>>> {
-        // TODO: Use rbx_reflection to look up property descriptors
-        Ok(None)
+        let tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(self.id).ok_or_else(|| {
+            mlua::Error::external(format!("Cannot access {} on a destroyed instance", name))
+        })?;
+
+        let descriptor = match find_canonical_property_descriptor(&instance.class, name) {
+            Some(descriptor) => descriptor,
+            None => return Ok(None),
+        };
+
+        match descriptor.scriptability {
+            Scriptability::Read | Scriptability::ReadWrite => {}
+            _ => return Ok(None),
+        }
+
+        let value = instance
+            .properties
+            .get(descriptor.name.as_ref())
+            .or_else(|| find_default_property(&instance.class, &descriptor.name));
+
+        if is_brick_color_alias(&instance.class, name, descriptor) {
+            let brick_color = match value {
+                Some(Variant::Color3(color)) => BrickColorValue::from_color3(*color),
+                Some(Variant::Color3uint8(color)) => {
+                    BrickColorValue::from_color3(color3uint8_to_color3(*color))
+                }
+                _ => return Ok(None),
+            };
+
+            return brick_color.to_lua(context).map(Some);
+        }
+
+        match (&descriptor.data_type, value) {
+            (DataType::Enum(enum_name), Some(Variant::Enum(value))) => {
+                enum_to_lua(context, enum_name, *value).map(Some)
+            }
+            (_, Some(Variant::Ref(referent))) => {
+                self.ref_to_lua(context, &tree, *referent).map(Some)
+            }
+            (_, Some(value)) => rbxvalue_to_lua(context, value).map(Some),
+
+            // Unset references, like a Model without a PrimaryPart, are nil.
+            (DataType::Value(VariantType::Ref), None) if is_serialized(descriptor) => {
+                Ok(Some(mlua::Value::Nil))
+            }
+
+            // Some properties, like BasePart.Position or DataModel.Workspace,
+            // are derived at runtime and never stored or given a default.
+            // These fall back to looking up children instead.
+            (_, None) => Ok(None),
+        }
+    }
+
+    fn set_property(&self, name: &str, value: mlua::Value<'_>) -> mlua::Result<()> {
+        let mut tree = self.tree.lock().unwrap();
+
+        let instance = tree.get_by_ref(self.id).ok_or_else(|| {
+            mlua::Error::external(format!("Cannot set {} on a destroyed instance", name))
+        })?;
+
+        let descriptor =
+            find_canonical_property_descriptor(&instance.class, name).ok_or_else(|| {
+                mlua::Error::external(format!(
+                    "'{}' is not a valid member of {}",
+                    name, instance.class
+                ))
+            })?;
+
+        match descriptor.scriptability {
+            Scriptability::ReadWrite | Scriptability::Write => {}
+            Scriptability::Read => {
+                return Err(mlua::Error::external(format!(
+                    "{}.{} is read-only.",
+                    instance.class, name
+                )))
+            }
+            _ => {
+                return Err(mlua::Error::external(format!(
+                    "{}.{} cannot be set from Lua.",
+                    instance.class, name
+                )))
+            }
+        }
+
+        let value = match &descriptor.data_type {
+            _ if is_brick_color_alias(&instance.class, name, descriptor) => {
+                lua_to_rbxvalue(VariantType::BrickColor, value).map(|value| match value {
+                    Variant::BrickColor(brick_color) => Variant::Color3(color3uint8_to_color3(
+                        BrickColorValue::new(brick_color).color3uint8(),
+                    )),
+                    other => other,
+                })
+            }
+            DataType::Value(VariantType::Ref) => self.lua_to_ref(&tree, value),
+            DataType::Value(ty) => lua_to_rbxvalue(*ty, value),
+            DataType::Enum(enum_name) => lua_to_enum(enum_name, value),
+            _ => Err(mlua::Error::external(
+                "The type of this property is unknown to Remodel",
+            )),
+        };
+
+        let value = value.map_err(|err| {
+            mlua::Error::external(format!(
+                "Invalid value for {}.{}: {}",
+                instance.class, name, err
+            ))
+        })?;
+
+        let instance = tree.get_by_ref_mut(self.id).unwrap();
+        instance
+            .properties
+            .insert(descriptor.name.clone().into_owned(), value);
+
+        Ok(())
     }
 
     fn meta_to_string<'lua>(&self, context: &'lua Lua) -> mlua::Result<mlua::Value<'lua>> {
@@ -354,7 +847,7 @@ impl LuaInstance {
                     return Ok(value);
                 }
 
-                if let Some(child) = self.find_first_child(key)? {
+                if let Some(child) = self.find_first_child(key, false)? {
                     return child.to_lua(context);
                 }
 
@@ -377,17 +870,17 @@ impl LuaInstance {
             "ClassName" => Err(mlua::Error::external("Instance.ClassName is read-only.")),
             "Parent" => self.set_parent(context, value),
 
-            // Setting unknown keys is an error.
-            _ => Err(mlua::Error::external(format!(
-                "'{}' is not a valid member of Instance",
-                key
-            ))),
+            // Setting unknown keys falls back to properties, which will error
+            // if the property does not exist.
+            _ => self.set_property(key, value),
         }
     }
 }
 
 impl UserData for LuaInstance {
     fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("AddTag", |_context, this, tag: String| this.add_tag(&tag));
+
         methods.add_method("ClearAllChildren", |_context, this, _args: ()| {
             this.clear_all_children()
         });
@@ -396,14 +889,46 @@ impl UserData for LuaInstance {
 
         methods.add_method("Destroy", |_context, this, _args: ()| this.destroy());
 
-        methods.add_method("FindFirstChild", |_context, this, name: String| {
-            this.find_first_child(&name)
+        methods.add_method("FindFirstAncestor", |_context, this, name: String| {
+            this.find_first_ancestor(&name)
         });
 
+        methods.add_method(
+            "FindFirstAncestorOfClass",
+            |_context, this, class: String| this.find_first_ancestor_of_class(&class),
+        );
+
+        methods.add_method(
+            "FindFirstAncestorWhichIsA",
+            |_context, this, class: String| this.find_first_ancestor_which_is_a(&class),
+        );
+
+        methods.add_method(
+            "FindFirstChild",
+            |_context, this, (name, recursive): (String, Option<bool>)| {
+                this.find_first_child(&name, recursive.unwrap_or(false))
+            },
+        );
+
         methods.add_method("FindFirstChildOfClass", |_context, this, class: String| {
             this.find_first_child_of_class(&class)
         });
 
+        methods.add_method(
+            "FindFirstChildWhichIsA",
+            |_context, this, (class, recursive): (String, Option<bool>)| {
+                this.find_first_child_which_is_a(&class, recursive.unwrap_or(false))
+            },
+        );
+
+        methods.add_method("GetAttribute", |context, this, name: String| {
+            this.get_attribute(context, &name)
+        });
+
+        methods.add_method("GetAttributes", |context, this, _args: ()| {
+            this.get_attributes(context)
+        });
+
         methods.add_method("GetFullName", |_context, this, _args: ()| {
             this.get_full_name()
         });
@@ -420,6 +945,35 @@ impl UserData for LuaInstance {
             this.get_service(&name)
         });
 
+        methods.add_method("GetTagged", |_context, this, tag: String| {
+            this.get_tagged(&tag)
+        });
+
+        methods.add_method("GetTags", |_context, this, _args: ()| this.get_tags());
+
+        methods.add_method("HasTag", |_context, this, tag: String| this.has_tag(&tag));
+
+        methods.add_method("IsA", |_context, this, class: String| this.is_a(&class));
+
+        methods.add_method("IsAncestorOf", |_context, this, other: LuaInstance| {
+            this.is_ancestor_of(&other)
+        });
+
+        methods.add_method("IsDescendantOf", |_context, this, other: LuaInstance| {
+            this.is_descendant_of(&other)
+        });
+
+        methods.add_method("RemoveTag", |_context, this, tag: String| {
+            this.remove_tag(&tag)
+        });
+
+        methods.add_method(
+            "SetAttribute",
+            |_context, this, (name, value): (String, mlua::Value<'lua>)| {
+                this.set_attribute(&name, value)
+            },
+        );
+
         methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
             this.meta_to_string(context)
         });
@@ -441,3 +995,101 @@ impl UserData for LuaInstance {
         );
     }
 }
+
+/// Properties like `BasePart.BrickColor` are aliases for a property with a
+/// different type, in this case the Color3 `Color`. Values need converting
+/// between the two when they're accessed through the alias.
+fn is_brick_color_alias(
+    class_name: &str,
+    property_name: &str,
+    canonical: &PropertyDescriptor<'_>,
+) -> bool {
+    let requested_type =
+        find_property_descriptor(class_name, property_name).map(|descriptor| &descriptor.data_type);
+
+    matches!(
+        requested_type,
+        Some(DataType::Value(VariantType::BrickColor))
+    ) && matches!(canonical.data_type, DataType::Value(VariantType::Color3))
+}
+
+fn is_serialized(descriptor: &PropertyDescriptor<'_>) -> bool {
+    matches!(
+        descriptor.kind,
+        PropertyKind::Canonical {
+            serialization: PropertySerialization::Serializes
+                | PropertySerialization::SerializesAs(_),
+        }
+    )
+}
+
+/// Reads the attributes of an instance. Instances loaded from files hold them
+/// in `Attributes`, but they may also be left in their serialized form in
+/// `AttributesSerialize`, like when set with `remodel.setRawProperty`.
+fn read_attributes(instance: &rbx_dom_weak::Instance) -> mlua::Result<Attributes> {
+    match instance.properties.get("Attributes") {
+        Some(Variant::Attributes(attributes)) => return Ok(attributes.clone()),
+        Some(_) => {
+            return Err(mlua::Error::external(format!(
+                "Attributes of {} are not stored as attributes",
+                instance.name
+            )))
+        }
+        None => {}
+    }
+
+    match instance.properties.get("AttributesSerialize") {
+        Some(Variant::BinaryString(bytes)) => Attributes::from_reader(AsRef::<[u8]>::as_ref(bytes))
+            .map_err(|err| {
+                mlua::Error::external(format!(
+                    "Could not decode attributes of {}: {}",
+                    instance.name, err
+                ))
+            }),
+        _ => Ok(Attributes::new()),
+    }
+}
+
+/// Applies the same rules to attribute names as Roblox.
+fn validate_attribute_name(name: &str) -> mlua::Result<()> {
+    if name.is_empty() || name.len() > 100 {
+        return Err(mlua::Error::external(
+            "Attribute names must be between 1 and 100 characters long",
+        ));
+    }
+
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(mlua::Error::external(format!(
+            "Attribute name '{}' may only contain letters, numbers, and underscores",
+            name
+        )));
+    }
+
+    if name.starts_with("RBX") {
+        return Err(mlua::Error::external(format!(
+            "Attribute name '{}' is reserved by Roblox",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reads the CollectionService tags of an instance, which may also be left in
+/// their serialized form if they were set with `remodel.setRawProperty`.
+fn read_tags(instance: &rbx_dom_weak::Instance) -> mlua::Result<Tags> {
+    match instance.properties.get("Tags") {
+        Some(Variant::Tags(tags)) => Ok(tags.clone()),
+        Some(Variant::BinaryString(bytes)) => Tags::decode(bytes.as_ref()).map_err(|err| {
+            mlua::Error::external(format!(
+                "Could not decode tags of {}: {}",
+                instance.name, err
+            ))
+        }),
+        Some(_) => Err(mlua::Error::external(format!(
+            "Tags of {} are not stored as tags",
+            instance.name
+        ))),
+        None => Ok(Tags::new()),
+    }
+}
@@ -3,12 +3,17 @@ mod instance;
 
 use std::sync::Arc;
 
-use mlua::{Lua, UserData, UserDataMethods};
+use mlua::{Lua, MetaMethod, UserData, UserDataMethods, Value as LuaValue, Variadic};
 use rbx_dom_weak::InstanceBuilder;
 
 use crate::{
+    brick_color,
     remodel_context::RemodelContext,
-    value::{Color3Value, Vector3Value, Vector3int16Value},
+    value::{
+        BrickColorValue, Color3Value, ColorSequenceKeypointValue, ColorSequenceValue, EnumValue,
+        NumberRangeValue, NumberSequenceKeypointValue, NumberSequenceValue, UDim2Value, UDimValue,
+        Vector2Value, Vector2int16Value, Vector3Value, Vector3int16Value,
+    },
 };
 
 use cframe::CFrameUserData;
@@ -19,10 +24,25 @@ pub struct RobloxApi;
 impl RobloxApi {
     pub fn inject(context: &Lua) -> mlua::Result<()> {
         context.globals().set("Instance", Instance)?;
+        context.globals().set("Vector2", Vector2)?;
+        context.globals().set("Vector2int16", Vector2int16)?;
         context.globals().set("Vector3", Vector3)?;
         context.globals().set("Vector3int16", Vector3int16)?;
         context.globals().set("Color3", Color3)?;
+        context.globals().set("BrickColor", BrickColor)?;
+        context.globals().set("UDim", UDim)?;
+        context.globals().set("UDim2", UDim2)?;
         context.globals().set("CFrame", CFrameUserData)?;
+        context.globals().set("NumberRange", NumberRange)?;
+        context.globals().set("ColorSequence", ColorSequence)?;
+        context
+            .globals()
+            .set("ColorSequenceKeypoint", ColorSequenceKeypoint)?;
+        context.globals().set("NumberSequence", NumberSequence)?;
+        context
+            .globals()
+            .set("NumberSequenceKeypoint", NumberSequenceKeypoint)?;
+        context.globals().set("Enum", Enums)?;
 
         Ok(())
     }
@@ -55,6 +75,48 @@ impl UserData for Instance {
     }
 }
 
+struct Vector2;
+
+impl UserData for Vector2 {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function("new", |_context, (x, y): (Option<f32>, Option<f32>)| {
+            Ok(Vector2Value::new(rbx_dom_weak::types::Vector2::new(
+                x.unwrap_or(0.0),
+                y.unwrap_or(0.0),
+            )))
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |_context, _this, key: String| {
+            let (x, y) = match key.as_str() {
+                "zero" => (0.0, 0.0),
+                "one" => (1.0, 1.0),
+                "xAxis" => (1.0, 0.0),
+                "yAxis" => (0.0, 1.0),
+                _ => {
+                    return Err(mlua::Error::external(format!(
+                        "'{}' is not a valid member of Vector2",
+                        key
+                    )))
+                }
+            };
+
+            Ok(Vector2Value::new(rbx_dom_weak::types::Vector2::new(x, y)))
+        });
+    }
+}
+
+struct Vector2int16;
+
+impl UserData for Vector2int16 {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function("new", |_context, (x, y): (Option<i16>, Option<i16>)| {
+            Ok(Vector2int16Value::new(
+                rbx_dom_weak::types::Vector2int16::new(x.unwrap_or(0), y.unwrap_or(0)),
+            ))
+        })
+    }
+}
+
 struct Vector3;
 
 impl UserData for Vector3 {
@@ -69,6 +131,26 @@ impl UserData for Vector3 {
                 )))
             },
         );
+
+        methods.add_meta_method(MetaMethod::Index, |_context, _this, key: String| {
+            let (x, y, z) = match key.as_str() {
+                "zero" => (0.0, 0.0, 0.0),
+                "one" => (1.0, 1.0, 1.0),
+                "xAxis" => (1.0, 0.0, 0.0),
+                "yAxis" => (0.0, 1.0, 0.0),
+                "zAxis" => (0.0, 0.0, 1.0),
+                _ => {
+                    return Err(mlua::Error::external(format!(
+                        "'{}' is not a valid member of Vector3",
+                        key
+                    )))
+                }
+            };
+
+            Ok(Vector3Value::new(rbx_dom_weak::types::Vector3::new(
+                x, y, z,
+            )))
+        });
     }
 }
 
@@ -107,3 +189,288 @@ impl UserData for Color3 {
         });
     }
 }
+
+struct BrickColor;
+
+impl UserData for BrickColor {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function(
+            "new",
+            |_context, arguments: Variadic<LuaValue<'_>>| match arguments.as_slice() {
+                [LuaValue::String(name)] => {
+                    Ok(rbx_dom_weak::types::BrickColor::from_name(name.to_str()?)
+                        .map(BrickColorValue::new)
+                        .unwrap_or_else(BrickColorValue::default_color))
+                }
+                [LuaValue::UserData(user_data)] if user_data.is::<Color3Value>() => {
+                    let color = user_data.borrow::<Color3Value>()?.inner();
+                    Ok(BrickColorValue::from_color3(color))
+                }
+                [number] => {
+                    let number = try_into_f32(number.clone()).ok_or_else(|| {
+                        mlua::Error::external(
+                            "invalid argument #1 to 'new' (string, number or Color3 expected)",
+                        )
+                    })?;
+
+                    Ok(rbx_dom_weak::types::BrickColor::from_number(number as u16)
+                        .map(BrickColorValue::new)
+                        .unwrap_or_else(BrickColorValue::default_color))
+                }
+                [r, g, b] => match (
+                    try_into_f32(r.clone()),
+                    try_into_f32(g.clone()),
+                    try_into_f32(b.clone()),
+                ) {
+                    (Some(r), Some(g), Some(b)) => Ok(BrickColorValue::from_color3(
+                        rbx_dom_weak::types::Color3::new(r, g, b),
+                    )),
+                    _ => Err(mlua::Error::external(
+                        "invalid arguments to 'new' (3 numbers expected)",
+                    )),
+                },
+                _ => Err(mlua::Error::external(
+                    "invalid argument #1 to 'new' (string, number or Color3 expected)",
+                )),
+            },
+        );
+        methods.add_function("palette", |_context, index: i64| {
+            BrickColorValue::palette(index)
+        });
+        // Going through math.random means scripts can use math.randomseed to
+        // get reproducible colors.
+        methods.add_function("random", |context, _: ()| {
+            let math: mlua::Table = context.globals().get("math")?;
+            let random: mlua::Function = math.get("random")?;
+            let index: i64 = random.call(brick_color::PALETTE.len())?;
+
+            BrickColorValue::palette(index - 1)
+        });
+    }
+}
+
+struct UDim;
+
+impl UserData for UDim {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function(
+            "new",
+            |_context, (scale, offset): (Option<f32>, Option<i32>)| {
+                Ok(UDimValue::new(rbx_dom_weak::types::UDim::new(
+                    scale.unwrap_or(0.0),
+                    offset.unwrap_or(0),
+                )))
+            },
+        );
+    }
+}
+
+struct UDim2;
+
+impl UserData for UDim2 {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function(
+            "new",
+            |_context,
+             arguments: (
+                Option<LuaValue<'_>>,
+                Option<LuaValue<'_>>,
+                Option<f32>,
+                Option<i32>,
+            )| {
+                match arguments {
+                    (Some(LuaValue::UserData(x)), Some(LuaValue::UserData(y)), None, None) => {
+                        let x = x.borrow::<UDimValue>()?.inner();
+                        let y = y.borrow::<UDimValue>()?.inner();
+
+                        Ok(UDim2Value::new(rbx_dom_weak::types::UDim2::new(x, y)))
+                    }
+                    (x_scale, x_offset, y_scale, y_offset) => {
+                        let x_scale = x_scale.map(try_into_f32).unwrap_or(Some(0.0));
+                        let x_offset = x_offset.map(try_into_f32).unwrap_or(Some(0.0));
+
+                        match (x_scale, x_offset) {
+                            (Some(x_scale), Some(x_offset)) => {
+                                Ok(UDim2Value::new(rbx_dom_weak::types::UDim2::new(
+                                    rbx_dom_weak::types::UDim::new(x_scale, x_offset as i32),
+                                    rbx_dom_weak::types::UDim::new(
+                                        y_scale.unwrap_or(0.0),
+                                        y_offset.unwrap_or(0),
+                                    ),
+                                )))
+                            }
+                            _ => Err(mlua::Error::external(
+                                "invalid arguments to 'new' (numbers or UDims expected)",
+                            )),
+                        }
+                    }
+                }
+            },
+        );
+        methods.add_function(
+            "fromScale",
+            |_context, (x, y): (Option<f32>, Option<f32>)| {
+                Ok(UDim2Value::new(rbx_dom_weak::types::UDim2::new(
+                    rbx_dom_weak::types::UDim::new(x.unwrap_or(0.0), 0),
+                    rbx_dom_weak::types::UDim::new(y.unwrap_or(0.0), 0),
+                )))
+            },
+        );
+        methods.add_function(
+            "fromOffset",
+            |_context, (x, y): (Option<i32>, Option<i32>)| {
+                Ok(UDim2Value::new(rbx_dom_weak::types::UDim2::new(
+                    rbx_dom_weak::types::UDim::new(0.0, x.unwrap_or(0)),
+                    rbx_dom_weak::types::UDim::new(0.0, y.unwrap_or(0)),
+                )))
+            },
+        );
+    }
+}
+
+struct NumberRange;
+
+impl UserData for NumberRange {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function("new", |_context, (min, max): (f32, Option<f32>)| {
+            NumberRangeValue::from_min_max(min, max.unwrap_or(min))
+        });
+    }
+}
+
+struct ColorSequence;
+
+impl UserData for ColorSequence {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function(
+            "new",
+            |_context, (first, second): (LuaValue<'_>, Option<Color3Value>)| {
+                use rbx_dom_weak::types::ColorSequenceKeypoint;
+
+                let keypoints = match first {
+                    LuaValue::Table(table) => table
+                        .sequence_values::<ColorSequenceKeypointValue>()
+                        .collect::<mlua::Result<Vec<_>>>()?,
+                    LuaValue::UserData(user_data) => {
+                        let start = user_data.borrow::<Color3Value>()?.inner();
+                        let end = second.map(|end| end.inner()).unwrap_or(start);
+
+                        vec![
+                            ColorSequenceKeypointValue::new(ColorSequenceKeypoint::new(0.0, start)),
+                            ColorSequenceKeypointValue::new(ColorSequenceKeypoint::new(1.0, end)),
+                        ]
+                    }
+                    _ => {
+                        return Err(mlua::Error::external(
+                            "invalid argument #1 to 'new' (Color3 or table expected)",
+                        ))
+                    }
+                };
+
+                ColorSequenceValue::from_keypoints(keypoints)
+            },
+        );
+    }
+}
+
+struct ColorSequenceKeypoint;
+
+impl UserData for ColorSequenceKeypoint {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function("new", |_context, (time, color): (f32, Color3Value)| {
+            Ok(ColorSequenceKeypointValue::new(
+                rbx_dom_weak::types::ColorSequenceKeypoint::new(time, color.inner()),
+            ))
+        });
+    }
+}
+
+struct NumberSequence;
+
+impl UserData for NumberSequence {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function(
+            "new",
+            |_context, (first, second): (LuaValue<'_>, Option<f32>)| {
+                use rbx_dom_weak::types::NumberSequenceKeypoint;
+
+                let keypoints = match first {
+                    LuaValue::Table(table) => table
+                        .sequence_values::<NumberSequenceKeypointValue>()
+                        .collect::<mlua::Result<Vec<_>>>()?,
+                    value => {
+                        let start = try_into_f32(value).ok_or_else(|| {
+                            mlua::Error::external(
+                                "invalid argument #1 to 'new' (number or table expected)",
+                            )
+                        })?;
+                        let end = second.unwrap_or(start);
+
+                        vec![
+                            NumberSequenceKeypointValue::new(NumberSequenceKeypoint::new(
+                                0.0, start, 0.0,
+                            )),
+                            NumberSequenceKeypointValue::new(NumberSequenceKeypoint::new(
+                                1.0, end, 0.0,
+                            )),
+                        ]
+                    }
+                };
+
+                NumberSequenceValue::from_keypoints(keypoints)
+            },
+        );
+    }
+}
+
+struct NumberSequenceKeypoint;
+
+impl UserData for NumberSequenceKeypoint {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_function(
+            "new",
+            |_context, (time, value, envelope): (f32, f32, Option<f32>)| {
+                Ok(NumberSequenceKeypointValue::new(
+                    rbx_dom_weak::types::NumberSequenceKeypoint::new(
+                        time,
+                        value,
+                        envelope.unwrap_or(0.0),
+                    ),
+                ))
+            },
+        );
+    }
+}
+
+struct Enums;
+
+impl UserData for Enums {
+    fn add_methods<'lua, T: UserDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.add_method("GetEnums", |_context, _this, _args: ()| {
+            let database = rbx_reflection_database::get();
+
+            let mut enums: Vec<_> = database
+                .enums
+                .keys()
+                .filter_map(|name| EnumValue::from_name(name))
+                .collect();
+            enums.sort_by_key(|value| value.name());
+
+            Ok(enums)
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |_context, _this, key: String| {
+            EnumValue::from_name(&key).ok_or_else(|| {
+                mlua::Error::external(format!("'{}' is not a valid member of Enum", key))
+            })
+        });
+    }
+}
+
+fn try_into_f32(value: LuaValue<'_>) -> Option<f32> {
+    match value {
+        LuaValue::Number(num) => Some(num as f32),
+        LuaValue::Integer(int) => Some(int as f32),
+        _ => None,
+    }
+}
@@ -0,0 +1,227 @@
+//! Describes the instances in a tree, for looking inside of a place or model
+//! without writing a script.
+
+use std::collections::HashSet;
+
+use rbx_dom_weak::{
+    types::{Ref, Variant},
+    Instance, WeakDom,
+};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+use crate::{
+    dom_util::instance_path,
+    reflection::{find_canonical_property_descriptor, find_default_property, is_a},
+    variant_json::variant_to_json,
+};
+
+pub struct TreeView<'a> {
+    dom: &'a WeakDom,
+    roots: Vec<Ref>,
+    max_depth: Option<usize>,
+    properties: Vec<String>,
+
+    /// The instances to show, if only some of them are. Ancestors of matching
+    /// instances are shown too, so that it's clear where they are.
+    shown: Option<HashSet<Ref>>,
+}
+
+impl<'a> TreeView<'a> {
+    /// Describes the instances in `dom`, starting at the instance at the given
+    /// path like `Workspace.Map`, or at the children of the root otherwise.
+    /// When `class_name` is given, only instances that are of that class or
+    /// one of its subclasses are shown.
+    pub fn new(
+        dom: &'a WeakDom,
+        path: Option<&str>,
+        max_depth: Option<usize>,
+        class_name: Option<&str>,
+        properties: Vec<String>,
+    ) -> anyhow::Result<Self> {
+        let roots = match path {
+            Some(path) => vec![find_by_path(dom, path)
+                .ok_or_else(|| anyhow::anyhow!("There is no instance at {}", path))?],
+            None => dom.root().children().to_vec(),
+        };
+
+        let mut view = TreeView {
+            dom,
+            roots,
+            max_depth,
+            properties,
+            shown: None,
+        };
+
+        if let Some(class_name) = class_name {
+            if !rbx_reflection_database::get()
+                .classes
+                .contains_key(class_name)
+            {
+                anyhow::bail!("'{}' is not a valid class name", class_name);
+            }
+
+            let mut shown = HashSet::new();
+            for &root in &view.roots {
+                view.find_shown(root, 0, class_name, &mut shown);
+            }
+            view.shown = Some(shown);
+        }
+
+        Ok(view)
+    }
+
+    fn find_shown(
+        &self,
+        id: Ref,
+        depth: usize,
+        class_name: &str,
+        shown: &mut HashSet<Ref>,
+    ) -> bool {
+        let instance = self.dom.get_by_ref(id).unwrap();
+        let mut is_shown = is_a(&instance.class, class_name);
+
+        if self.max_depth.map_or(true, |max_depth| depth < max_depth) {
+            for &child in instance.children() {
+                is_shown |= self.find_shown(child, depth + 1, class_name, shown);
+            }
+        }
+
+        if is_shown {
+            shown.insert(id);
+        }
+
+        is_shown
+    }
+
+    fn children(&self, instance: &Instance, depth: usize) -> Vec<Ref> {
+        if self.max_depth.map_or(false, |max_depth| depth >= max_depth) {
+            return Vec::new();
+        }
+
+        self.visible(instance.children())
+    }
+
+    fn visible(&self, ids: &[Ref]) -> Vec<Ref> {
+        ids.iter()
+            .copied()
+            .filter(|id| match &self.shown {
+                Some(shown) => shown.contains(id),
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Describes the tree with one instance per line, indented by depth.
+    pub fn to_text(&self) -> String {
+        let mut output = String::new();
+
+        for id in self.visible(&self.roots) {
+            self.write_text(id, 0, &mut output);
+        }
+
+        output
+    }
+
+    fn write_text(&self, id: Ref, depth: usize, output: &mut String) {
+        let instance = self.dom.get_by_ref(id).unwrap();
+
+        output.push_str(&"  ".repeat(depth));
+        output.push_str(&format!("{} ({})", instance.name, instance.class));
+
+        for (name, value) in self.property_values(instance) {
+            output.push_str(&format!(" {}={}", name, value));
+        }
+
+        output.push('\n');
+
+        for child in self.children(instance, depth) {
+            self.write_text(child, depth + 1, output);
+        }
+    }
+
+    /// Describes the tree as a list of instances, each with their children.
+    pub fn to_json(&self) -> JsonValue {
+        JsonValue::Array(
+            self.visible(&self.roots)
+                .into_iter()
+                .map(|id| self.instance_json(id, 0))
+                .collect(),
+        )
+    }
+
+    fn instance_json(&self, id: Ref, depth: usize) -> JsonValue {
+        let instance = self.dom.get_by_ref(id).unwrap();
+        let mut object = JsonMap::new();
+
+        object.insert("name".to_owned(), JsonValue::from(instance.name.as_str()));
+        object.insert(
+            "className".to_owned(),
+            JsonValue::from(instance.class.as_str()),
+        );
+
+        if !self.properties.is_empty() {
+            object.insert(
+                "properties".to_owned(),
+                JsonValue::Object(self.property_values(instance).into_iter().collect()),
+            );
+        }
+
+        object.insert(
+            "children".to_owned(),
+            JsonValue::Array(
+                self.children(instance, depth)
+                    .into_iter()
+                    .map(|child| self.instance_json(child, depth + 1))
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(object)
+    }
+
+    /// Finds the values of the selected properties that this instance has.
+    /// References are given as the path of the instance they point to, and
+    /// values that can't be described are null.
+    fn property_values(&self, instance: &Instance) -> Vec<(String, JsonValue)> {
+        self.properties
+            .iter()
+            .filter_map(|name| {
+                let canonical_name = find_canonical_property_descriptor(&instance.class, name)
+                    .map(|descriptor| descriptor.name.as_ref())
+                    .unwrap_or(name.as_str());
+
+                let value = instance
+                    .properties
+                    .get(canonical_name)
+                    .or_else(|| find_default_property(&instance.class, canonical_name))?;
+
+                let json = match value {
+                    Variant::Ref(referent) if self.dom.get_by_ref(*referent).is_some() => {
+                        JsonValue::from(instance_path(self.dom, self.dom.root_ref(), *referent))
+                    }
+                    Variant::Ref(_) => JsonValue::Null,
+                    _ => variant_to_json(value).unwrap_or(JsonValue::Null),
+                };
+
+                Some((name.clone(), json))
+            })
+            .collect()
+    }
+}
+
+/// Finds an instance by the names of it and its ancestors, like
+/// `Workspace.Map`, starting from the children of the root.
+fn find_by_path(dom: &WeakDom, path: &str) -> Option<Ref> {
+    let mut current = dom.root_ref();
+
+    for name in path.split('.') {
+        current = dom
+            .get_by_ref(current)?
+            .children()
+            .iter()
+            .copied()
+            .find(|&child| dom.get_by_ref(child).unwrap().name == name)?;
+    }
+
+    Some(current)
+}
@@ -2,11 +2,19 @@
 
 use mlua::{
     Lua, MetaMethod, Result as LuaResult, ToLua, UserData, UserDataMethods, Value as LuaValue,
+    Variadic,
 };
 use rbx_dom_weak::types::{
-    CFrame, Color3, Color3uint8, Variant, VariantType, Vector3, Vector3int16,
+    BrickColor, CFrame, Color3, Color3uint8, ColorSequence, ColorSequenceKeypoint, Enum, Matrix3,
+    NumberRange, NumberSequence, NumberSequenceKeypoint, UDim, UDim2, Variant, VariantType,
+    Vector2, Vector2int16, Vector3, Vector3int16,
 };
+use rbx_reflection::EnumDescriptor;
+
+use crate::brick_color;
+use std::convert::TryFrom;
 use std::fmt;
+use std::iter::FromIterator;
 use std::ops;
 
 pub fn rbxvalue_to_lua<'lua>(context: &'lua Lua, value: &Variant) -> LuaResult<LuaValue<'lua>> {
@@ -21,31 +29,34 @@ pub fn rbxvalue_to_lua<'lua>(context: &'lua Lua, value: &Variant) -> LuaResult<L
         Variant::BinaryString(value) => {
             base64::encode(AsRef::<[u8]>::as_ref(value)).to_lua(context)
         }
-        Variant::BrickColor(_) => unimplemented_type("BrickColor"),
+        Variant::BrickColor(value) => BrickColorValue::new(*value).to_lua(context),
         Variant::Bool(value) => value.to_lua(context),
         Variant::CFrame(cframe) => CFrameValue::new(*cframe).to_lua(context),
         Variant::Color3(value) => Color3Value::new(*value).to_lua(context),
         Variant::Color3uint8(value) => Color3uint8Value::new(*value).to_lua(context),
-        Variant::ColorSequence(_) => unimplemented_type("ColorSequence"),
+        Variant::ColorSequence(value) => ColorSequenceValue::new(value.clone()).to_lua(context),
         Variant::Content(value) => AsRef::<str>::as_ref(value).to_lua(context),
-        Variant::Enum(_) => unimplemented_type("Enum"),
+        // Without knowing which enum a value belongs to, the best we can do is
+        // return its underlying number. Properties with a known enum type are
+        // converted with `enum_to_lua` instead.
+        Variant::Enum(value) => value.to_u32().to_lua(context),
         Variant::Float32(value) => value.to_lua(context),
         Variant::Float64(value) => value.to_lua(context),
         Variant::Int32(value) => value.to_lua(context),
         Variant::Int64(value) => value.to_lua(context),
-        Variant::NumberRange(_) => unimplemented_type("NumberRange"),
-        Variant::NumberSequence(_) => unimplemented_type("NumberSequence"),
+        Variant::NumberRange(value) => NumberRangeValue::new(*value).to_lua(context),
+        Variant::NumberSequence(value) => NumberSequenceValue::new(value.clone()).to_lua(context),
         Variant::PhysicalProperties(_) => unimplemented_type("PhysicalProperties"),
         Variant::Ray(_) => unimplemented_type("Ray"),
         Variant::Rect(_) => unimplemented_type("Rect"),
         Variant::Ref(_) => unimplemented_type("Ref"),
         Variant::SharedString(_) => unimplemented_type("SharedString"),
         Variant::String(value) => value.as_str().to_lua(context),
-        Variant::UDim(_) => unimplemented_type("UDim"),
-        Variant::UDim2(_) => unimplemented_type("UDim2"),
-        Variant::Vector2(_) => unimplemented_type("Vector2"),
-        Variant::Vector2int16(_) => unimplemented_type("Vector2int16"),
-        Variant::Vector3(_) => unimplemented_type("Vector3"),
+        Variant::UDim(value) => UDimValue::new(*value).to_lua(context),
+        Variant::UDim2(value) => UDim2Value::new(*value).to_lua(context),
+        Variant::Vector2(value) => Vector2Value::new(*value).to_lua(context),
+        Variant::Vector2int16(value) => Vector2int16Value::new(*value).to_lua(context),
+        Variant::Vector3(value) => Vector3Value::new(*value).to_lua(context),
         Variant::Vector3int16(value) => Vector3int16Value::new(*value).to_lua(context),
 
         _ => Err(mlua::Error::external(format!(
@@ -61,7 +72,7 @@ pub fn lua_to_rbxvalue(ty: VariantType, value: LuaValue<'_>) -> LuaResult<Varian
             Ok(Variant::String(lua_string.to_str()?.to_owned()))
         }
         (VariantType::Content, LuaValue::String(lua_string)) => {
-            Ok(Variant::String(lua_string.to_str()?.to_owned()))
+            Ok(Variant::Content(lua_string.to_str()?.into()))
         }
 
         (VariantType::Bool, LuaValue::Boolean(value)) => Ok(Variant::Bool(value)),
@@ -69,14 +80,14 @@ pub fn lua_to_rbxvalue(ty: VariantType, value: LuaValue<'_>) -> LuaResult<Varian
         (VariantType::Float32, LuaValue::Number(value)) => Ok(Variant::Float32(value as f32)),
         (VariantType::Float32, LuaValue::Integer(value)) => Ok(Variant::Float32(value as f32)),
 
-        (VariantType::Float64, LuaValue::Number(value)) => Ok(Variant::Float64(value as f64)),
+        (VariantType::Float64, LuaValue::Number(value)) => Ok(Variant::Float64(value)),
         (VariantType::Float64, LuaValue::Integer(value)) => Ok(Variant::Float64(value as f64)),
 
         (VariantType::Int32, LuaValue::Number(value)) => Ok(Variant::Int32(value as i32)),
         (VariantType::Int32, LuaValue::Integer(value)) => Ok(Variant::Int32(value as i32)),
 
         (VariantType::Int64, LuaValue::Number(value)) => Ok(Variant::Int64(value as i64)),
-        (VariantType::Int64, LuaValue::Integer(value)) => Ok(Variant::Int64(value as i64)),
+        (VariantType::Int64, LuaValue::Integer(value)) => Ok(Variant::Int64(value)),
 
         (VariantType::Color3, LuaValue::UserData(ref user_data)) => {
             let color = &*user_data.borrow::<Color3Value>()?;
@@ -87,6 +98,33 @@ pub fn lua_to_rbxvalue(ty: VariantType, value: LuaValue<'_>) -> LuaResult<Varian
             Ok(color.into())
         }
 
+        (VariantType::BrickColor, LuaValue::UserData(ref user_data)) => {
+            let brick_color = &*user_data.borrow::<BrickColorValue>()?;
+            Ok(brick_color.into())
+        }
+
+        (VariantType::ColorSequence, LuaValue::UserData(ref user_data)) => {
+            let sequence = &*user_data.borrow::<ColorSequenceValue>()?;
+            Ok(sequence.into())
+        }
+        (VariantType::NumberSequence, LuaValue::UserData(ref user_data)) => {
+            let sequence = &*user_data.borrow::<NumberSequenceValue>()?;
+            Ok(sequence.into())
+        }
+        (VariantType::NumberRange, LuaValue::UserData(ref user_data)) => {
+            let range = &*user_data.borrow::<NumberRangeValue>()?;
+            Ok(range.into())
+        }
+
+        (VariantType::Vector2, LuaValue::UserData(ref user_data)) => {
+            let vector2 = &*user_data.borrow::<Vector2Value>()?;
+            Ok(vector2.into())
+        }
+        (VariantType::Vector2int16, LuaValue::UserData(ref user_data)) => {
+            let vector2int16 = &*user_data.borrow::<Vector2int16Value>()?;
+            Ok(vector2int16.into())
+        }
+
         (VariantType::Vector3, LuaValue::UserData(ref user_data)) => {
             let vector3 = &*user_data.borrow::<Vector3Value>()?;
             Ok(vector3.into())
@@ -96,6 +134,26 @@ pub fn lua_to_rbxvalue(ty: VariantType, value: LuaValue<'_>) -> LuaResult<Varian
             Ok(vector3int16.into())
         }
 
+        (VariantType::UDim, LuaValue::UserData(ref user_data)) => {
+            let udim = &*user_data.borrow::<UDimValue>()?;
+            Ok(udim.into())
+        }
+        (VariantType::UDim2, LuaValue::UserData(ref user_data)) => {
+            let udim2 = &*user_data.borrow::<UDim2Value>()?;
+            Ok(udim2.into())
+        }
+
+        (VariantType::Enum, LuaValue::Integer(value)) => {
+            Ok(Variant::Enum(Enum::from_u32(value as u32)))
+        }
+        (VariantType::Enum, LuaValue::Number(value)) => {
+            Ok(Variant::Enum(Enum::from_u32(value as u32)))
+        }
+        (VariantType::Enum, LuaValue::UserData(ref user_data)) => {
+            let item = &*user_data.borrow::<EnumItemValue>()?;
+            Ok(item.into())
+        }
+
         (VariantType::BinaryString, LuaValue::String(lua_string)) => Ok(Variant::BinaryString(
             base64::decode(lua_string)
                 .map_err(mlua::Error::external)?
@@ -109,6 +167,109 @@ pub fn lua_to_rbxvalue(ty: VariantType, value: LuaValue<'_>) -> LuaResult<Varian
     }
 }
 
+/// Like `rbxvalue_to_lua`, but for the values of attributes. String attributes
+/// are decoded as `BinaryString` values, which Lua should see as plain strings.
+pub fn attribute_to_lua<'lua>(context: &'lua Lua, value: &Variant) -> LuaResult<LuaValue<'lua>> {
+    match value {
+        Variant::BinaryString(value) => context
+            .create_string(AsRef::<[u8]>::as_ref(value))
+            .map(LuaValue::String),
+        _ => rbxvalue_to_lua(context, value),
+    }
+}
+
+/// Turns a Lua value into a value that can be stored as an attribute, picking
+/// the attribute type from the type of the Lua value.
+pub fn lua_to_attribute(value: LuaValue<'_>) -> LuaResult<Variant> {
+    let ty = match &value {
+        LuaValue::String(_) => VariantType::String,
+        LuaValue::Boolean(_) => VariantType::Bool,
+        LuaValue::Integer(_) | LuaValue::Number(_) => VariantType::Float64,
+        LuaValue::UserData(user_data) => {
+            if user_data.is::<BrickColorValue>() {
+                VariantType::BrickColor
+            } else if user_data.is::<Color3Value>() {
+                VariantType::Color3
+            } else if user_data.is::<ColorSequenceValue>() {
+                VariantType::ColorSequence
+            } else if user_data.is::<NumberRangeValue>() {
+                VariantType::NumberRange
+            } else if user_data.is::<NumberSequenceValue>() {
+                VariantType::NumberSequence
+            } else if user_data.is::<UDimValue>() {
+                VariantType::UDim
+            } else if user_data.is::<UDim2Value>() {
+                VariantType::UDim2
+            } else if user_data.is::<Vector2Value>() {
+                VariantType::Vector2
+            } else if user_data.is::<Vector3Value>() {
+                VariantType::Vector3
+            } else {
+                return Err(mlua::Error::external(
+                    "this type cannot be stored as an attribute",
+                ));
+            }
+        }
+        _ => {
+            return Err(mlua::Error::external(format!(
+                "a {} cannot be stored as an attribute",
+                value.type_name()
+            )))
+        }
+    };
+
+    lua_to_rbxvalue(ty, value)
+}
+
+/// Converts a value of the enum with the given name into an EnumItem. If the
+/// enum or item is not known to the reflection database, the value is returned
+/// as a number instead.
+pub fn enum_to_lua<'lua>(
+    context: &'lua Lua,
+    enum_name: &str,
+    value: Enum,
+) -> LuaResult<LuaValue<'lua>> {
+    match EnumValue::from_name(enum_name).and_then(|ty| ty.item_from_value(value.to_u32())) {
+        Some(item) => item.to_lua(context),
+        None => value.to_u32().to_lua(context),
+    }
+}
+
+/// Converts an EnumItem, the name of an item, or the number of an item into a
+/// value of the enum with the given name, validating that it is a member of
+/// that enum.
+pub fn lua_to_enum(enum_name: &str, value: LuaValue<'_>) -> LuaResult<Variant> {
+    let enum_value = EnumValue::from_name(enum_name)
+        .ok_or_else(|| mlua::Error::external(format!("'{}' is not a valid Enum", enum_name)))?;
+
+    let item = match &value {
+        LuaValue::UserData(user_data) => {
+            let item = *user_data.borrow::<EnumItemValue>()?;
+
+            if item.enum_type.name() != enum_name {
+                return Err(mlua::Error::external(format!(
+                    "Expected an EnumItem of {}, got {}",
+                    enum_name, item
+                )));
+            }
+
+            Some(item)
+        }
+        LuaValue::String(name) => enum_value.item(name.to_str()?),
+        LuaValue::Integer(number) => enum_value.item_from_value(*number as u32),
+        LuaValue::Number(number) => enum_value.item_from_value(*number as u32),
+        _ => None,
+    };
+
+    match item {
+        Some(item) => Ok((&item).into()),
+        None => Err(mlua::Error::external(format!(
+            "The Lua value {:?} is not a valid EnumItem of {}",
+            value, enum_name
+        ))),
+    }
+}
+
 pub fn type_from_str(name: &str) -> Option<VariantType> {
     use VariantType::*;
 
@@ -158,6 +319,10 @@ impl Color3Value {
         Self(value)
     }
 
+    pub fn inner(&self) -> Color3 {
+        self.0
+    }
+
     fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
         match key {
             "r" | "R" => self.0.r.to_lua(context),
@@ -179,6 +344,10 @@ impl From<&Color3Value> for Variant {
 
 impl UserData for Color3Value {
     fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+
         methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
             this.to_string().to_lua(context)
         });
@@ -219,66 +388,309 @@ impl UserData for Color3uint8Value {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct Vector3Value(Vector3);
+pub struct BrickColorValue(BrickColor);
 
-impl fmt::Display for Vector3Value {
+impl fmt::Display for BrickColorValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}, {}, {}", self.0.x, self.0.y, self.0.z)
+        write!(f, "{}", self.0)
     }
 }
 
-impl Vector3Value {
-    pub fn new(value: Vector3) -> Self {
+impl BrickColorValue {
+    pub fn new(value: BrickColor) -> Self {
         Self(value)
     }
 
-    pub fn inner(&self) -> Vector3 {
+    /// Mirrors Roblox's fallback for names and numbers it doesn't recognize.
+    pub fn default_color() -> Self {
+        Self(BrickColor::MediumStoneGrey)
+    }
+
+    pub fn from_color3(color: Color3) -> Self {
+        Self(brick_color::from_color3uint8(color3_to_uint8(color)))
+    }
+
+    pub fn palette(index: i64) -> LuaResult<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| brick_color::PALETTE.get(index))
+            .and_then(|number| BrickColor::from_number(*number))
+            .map(Self)
+            .ok_or_else(|| {
+                mlua::Error::external(format!(
+                    "Palette index {} is out of range (0-{})",
+                    index,
+                    brick_color::PALETTE.len() - 1
+                ))
+            })
+    }
+
+    pub fn inner(&self) -> BrickColor {
         self.0
     }
 
+    pub fn color3uint8(&self) -> Color3uint8 {
+        brick_color::to_color3uint8(self.0)
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        let color = self.color3uint8();
+
+        match key {
+            "Name" => self.0.to_string().to_lua(context),
+            "Number" => (self.0 as u16).to_lua(context),
+            "Color" => Color3Value::new(color3uint8_to_color3(color)).to_lua(context),
+            "r" => (f32::from(color.r) / 255.0).to_lua(context),
+            "g" => (f32::from(color.g) / 255.0).to_lua(context),
+            "b" => (f32::from(color.b) / 255.0).to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of BrickColor",
+                key
+            ))),
+        }
+    }
+}
+
+impl From<&BrickColorValue> for Variant {
+    fn from(brick_color: &BrickColorValue) -> Variant {
+        Variant::BrickColor(brick_color.0)
+    }
+}
+
+impl UserData for BrickColorValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+pub fn color3_to_uint8(color: Color3) -> Color3uint8 {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    Color3uint8::new(channel(color.r), channel(color.g), channel(color.b))
+}
+
+pub fn color3uint8_to_color3(color: Color3uint8) -> Color3 {
+    Color3::new(
+        f32::from(color.r) / 255.0,
+        f32::from(color.g) / 255.0,
+        f32::from(color.b) / 255.0,
+    )
+}
+
+/// A number type that vectors are made of.
+trait VectorComponent: Copy {
+    fn from_integer(number: i64) -> Self;
+    fn from_number(number: f64) -> Self;
+}
+
+impl VectorComponent for f32 {
+    fn from_integer(number: i64) -> Self {
+        number as f32
+    }
+
+    fn from_number(number: f64) -> Self {
+        number as f32
+    }
+}
+
+impl VectorComponent for i16 {
+    fn from_integer(number: i64) -> Self {
+        number as i16
+    }
+
+    fn from_number(number: f64) -> Self {
+        number as i16
+    }
+}
+
+/// Vector types whose arithmetic is done one component at a time.
+trait ComponentVector: UserData + Copy + 'static {
+    type Component: VectorComponent;
+
+    /// The name of the type in Lua, for error messages.
+    const TYPE_NAME: &'static str;
+
+    fn splat(component: Self::Component) -> Self;
+
+    fn map(self, f: impl Fn(Self::Component) -> Self::Component) -> Self;
+
+    fn zip(
+        self,
+        other: Self,
+        f: impl Fn(Self::Component, Self::Component) -> Self::Component,
+    ) -> Self;
+
+    /// Reads one side of an arithmetic operation, which Roblox allows to be
+    /// either a vector or a number applied to every component.
+    fn operand(value: &LuaValue<'_>) -> LuaResult<Self> {
+        let component = match value {
+            LuaValue::Integer(number) => Self::Component::from_integer(*number),
+            LuaValue::Number(number) => Self::Component::from_number(*number),
+            LuaValue::UserData(user_data) => return Ok(*user_data.borrow::<Self>()?),
+            _ => {
+                return Err(mlua::Error::external(format!(
+                    "Expected a {} or number, got {:?}",
+                    Self::TYPE_NAME,
+                    value
+                )))
+            }
+        };
+
+        Ok(Self::splat(component))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vector2Value(Vector2);
+
+impl fmt::Display for Vector2Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.0.x, self.0.y)
+    }
+}
+
+impl Vector2Value {
+    pub fn new(value: Vector2) -> Self {
+        Self(value)
+    }
+
+    fn dot(self, other: Self) -> f32 {
+        self.0.x * other.0.x + self.0.y * other.0.y
+    }
+
+    fn magnitude(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Like in Roblox, the unit vector of a zero vector is made of NaNs.
+    fn unit(self) -> Self {
+        let magnitude = self.magnitude();
+        self.map(|a| a / magnitude)
+    }
+
+    fn lerp(self, goal: Self, alpha: f32) -> Self {
+        self + (goal - self).map(|a| a * alpha)
+    }
+
+    fn fuzzy_eq(self, other: Self, epsilon: f32) -> bool {
+        (self - other).magnitude() <= epsilon
+    }
+
     fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
         match key {
             "X" => self.0.x.to_lua(context),
             "Y" => self.0.y.to_lua(context),
-            "Z" => self.0.z.to_lua(context),
+            "Magnitude" => self.magnitude().to_lua(context),
+            "Unit" => self.unit().to_lua(context),
             _ => Err(mlua::Error::external(format!(
-                "'{}' is not a valid member of Vector3",
+                "'{}' is not a valid member of Vector2",
                 key
             ))),
         }
     }
 }
 
-impl ops::Add for Vector3Value {
-    type Output = Vector3Value;
+impl ComponentVector for Vector2Value {
+    type Component = f32;
+    const TYPE_NAME: &'static str = "Vector2";
+
+    fn splat(component: f32) -> Self {
+        Vector2Value::new(Vector2::new(component, component))
+    }
+
+    fn map(self, f: impl Fn(f32) -> f32) -> Self {
+        Vector2Value::new(Vector2::new(f(self.0.x), f(self.0.y)))
+    }
+
+    fn zip(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        Vector2Value::new(Vector2::new(f(self.0.x, other.0.x), f(self.0.y, other.0.y)))
+    }
+}
+
+impl ops::Add for Vector2Value {
+    type Output = Vector2Value;
     fn add(self, other: Self) -> Self::Output {
-        Vector3Value::new(Vector3::new(
-            self.0.x + other.0.x,
-            self.0.y + other.0.y,
-            self.0.z + other.0.z,
-        ))
+        self.zip(other, |a, b| a + b)
     }
 }
 
-impl ops::Sub for Vector3Value {
-    type Output = Vector3Value;
+impl ops::Sub for Vector2Value {
+    type Output = Vector2Value;
     fn sub(self, other: Self) -> Self::Output {
-        Vector3Value::new(Vector3::new(
-            self.0.x - other.0.x,
-            self.0.y - other.0.y,
-            self.0.z - other.0.z,
-        ))
+        self.zip(other, |a, b| a - b)
     }
 }
 
-impl From<&Vector3Value> for Variant {
-    fn from(vector: &Vector3Value) -> Variant {
-        Variant::Vector3(vector.0)
+impl ops::Mul for Vector2Value {
+    type Output = Vector2Value;
+    fn mul(self, other: Self) -> Self::Output {
+        self.zip(other, |a, b| a * b)
     }
 }
 
-impl UserData for Vector3Value {
+impl ops::Div for Vector2Value {
+    type Output = Vector2Value;
+    fn div(self, other: Self) -> Self::Output {
+        self.zip(other, |a, b| a / b)
+    }
+}
+
+impl ops::Neg for Vector2Value {
+    type Output = Vector2Value;
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl From<&Vector2Value> for Variant {
+    fn from(vector: &Vector2Value) -> Variant {
+        Variant::Vector2(vector.0)
+    }
+}
+
+impl UserData for Vector2Value {
     fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("Dot", |_context, this, other: Self| Ok(this.dot(other)));
+        methods.add_method("Cross", |_context, this, other: Self| {
+            Ok(this.0.x * other.0.y - this.0.y * other.0.x)
+        });
+        methods.add_method("Lerp", |_context, this, (goal, alpha): (Self, f32)| {
+            Ok(this.lerp(goal, alpha))
+        });
+        methods.add_method(
+            "FuzzyEq",
+            |_context, this, (other, epsilon): (Self, Option<f32>)| {
+                Ok(this.fuzzy_eq(other, epsilon.unwrap_or(1e-5)))
+            },
+        );
+        methods.add_method("Min", |_context, this, others: Variadic<Self>| {
+            Ok(others
+                .iter()
+                .fold(*this, |min, other| min.zip(*other, f32::min)))
+        });
+        methods.add_method("Max", |_context, this, others: Variadic<Self>| {
+            Ok(others
+                .iter()
+                .fold(*this, |max, other| max.zip(*other, f32::max)))
+        });
+        methods.add_method("Abs", |_context, this, _args: ()| Ok(this.map(f32::abs)));
+        methods.add_method(
+            "Floor",
+            |_context, this, _args: ()| Ok(this.map(f32::floor)),
+        );
+        methods.add_method("Ceil", |_context, this, _args: ()| Ok(this.map(f32::ceil)));
+        methods.add_method("Sign", |_context, this, _args: ()| {
+            Ok(this.map(|a| if a == 0.0 { 0.0 } else { a.signum() }))
+        });
+
         methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
             (this.0 == rhs.0).to_lua(context)
         });
@@ -288,6 +700,21 @@ impl UserData for Vector3Value {
         methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Self| {
             (*this - rhs).to_lua(context)
         });
+        methods.add_meta_function(
+            MetaMethod::Mul,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? * Self::operand(&rhs)?).to_lua(context)
+            },
+        );
+        methods.add_meta_function(
+            MetaMethod::Div,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? / Self::operand(&rhs)?).to_lua(context)
+            },
+        );
+        methods.add_meta_method(MetaMethod::Unm, |context, this, _arg: ()| {
+            (-*this).to_lua(context)
+        });
 
         methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
             this.meta_index(context, &key)
@@ -299,16 +726,16 @@ impl UserData for Vector3Value {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct Vector3int16Value(Vector3int16);
+pub struct Vector2int16Value(Vector2int16);
 
-impl fmt::Display for Vector3int16Value {
+impl fmt::Display for Vector2int16Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}, {}, {}", self.0.x, self.0.y, self.0.z)
+        write!(f, "{}, {}", self.0.x, self.0.y)
     }
 }
 
-impl Vector3int16Value {
-    pub fn new(value: Vector3int16) -> Self {
+impl Vector2int16Value {
+    pub fn new(value: Vector2int16) -> Self {
         Self(value)
     }
 
@@ -316,44 +743,80 @@ impl Vector3int16Value {
         match key {
             "X" => self.0.x.to_lua(context),
             "Y" => self.0.y.to_lua(context),
-            "Z" => self.0.z.to_lua(context),
             _ => Err(mlua::Error::external(format!(
-                "'{}' is not a valid member of Vector3",
+                "'{}' is not a valid member of Vector2int16",
                 key
             ))),
         }
     }
 }
 
-impl ops::Add for Vector3int16Value {
-    type Output = Vector3int16Value;
-    fn add(self, other: Self) -> Self::Output {
-        Vector3int16Value::new(Vector3int16::new(
-            self.0.x + other.0.x,
-            self.0.y + other.0.y,
-            self.0.z + other.0.z,
+impl ComponentVector for Vector2int16Value {
+    type Component = i16;
+    const TYPE_NAME: &'static str = "Vector2int16";
+
+    fn splat(component: i16) -> Self {
+        Vector2int16Value::new(Vector2int16::new(component, component))
+    }
+
+    fn map(self, f: impl Fn(i16) -> i16) -> Self {
+        Vector2int16Value::new(Vector2int16::new(f(self.0.x), f(self.0.y)))
+    }
+
+    fn zip(self, other: Self, f: impl Fn(i16, i16) -> i16) -> Self {
+        Vector2int16Value::new(Vector2int16::new(
+            f(self.0.x, other.0.x),
+            f(self.0.y, other.0.y),
         ))
     }
 }
 
-impl ops::Sub for Vector3int16Value {
-    type Output = Vector3int16Value;
+impl ops::Add for Vector2int16Value {
+    type Output = Vector2int16Value;
+    fn add(self, other: Self) -> Self::Output {
+        self.zip(other, i16::wrapping_add)
+    }
+}
+
+impl ops::Sub for Vector2int16Value {
+    type Output = Vector2int16Value;
     fn sub(self, other: Self) -> Self::Output {
-        Vector3int16Value::new(Vector3int16::new(
-            self.0.x - other.0.x,
-            self.0.y - other.0.y,
-            self.0.z - other.0.z,
-        ))
+        self.zip(other, i16::wrapping_sub)
     }
 }
 
-impl From<&Vector3int16Value> for Variant {
-    fn from(color: &Vector3int16Value) -> Variant {
-        Variant::Vector3int16(color.0)
+impl ops::Mul for Vector2int16Value {
+    type Output = Vector2int16Value;
+    fn mul(self, other: Self) -> Self::Output {
+        self.zip(other, i16::wrapping_mul)
     }
 }
 
-impl UserData for Vector3int16Value {
+impl ops::Div for Vector2int16Value {
+    type Output = LuaResult<Vector2int16Value>;
+    fn div(self, other: Self) -> Self::Output {
+        if other.0.x == 0 || other.0.y == 0 {
+            return Err(mlua::Error::external("Vector2int16 division by zero"));
+        }
+
+        Ok(self.zip(other, i16::wrapping_div))
+    }
+}
+
+impl ops::Neg for Vector2int16Value {
+    type Output = Vector2int16Value;
+    fn neg(self) -> Self::Output {
+        self.map(i16::wrapping_neg)
+    }
+}
+
+impl From<&Vector2int16Value> for Variant {
+    fn from(vector: &Vector2int16Value) -> Variant {
+        Variant::Vector2int16(vector.0)
+    }
+}
+
+impl UserData for Vector2int16Value {
     fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
         methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
             (this.0 == rhs.0).to_lua(context)
@@ -364,6 +827,21 @@ impl UserData for Vector3int16Value {
         methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Self| {
             (*this - rhs).to_lua(context)
         });
+        methods.add_meta_function(
+            MetaMethod::Mul,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? * Self::operand(&rhs)?).to_lua(context)
+            },
+        );
+        methods.add_meta_function(
+            MetaMethod::Div,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? / Self::operand(&rhs)?)?.to_lua(context)
+            },
+        );
+        methods.add_meta_method(MetaMethod::Unm, |context, this, _arg: ()| {
+            (-*this).to_lua(context)
+        });
 
         methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
             this.meta_index(context, &key)
@@ -375,84 +853,1411 @@ impl UserData for Vector3int16Value {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct CFrameValue(CFrame);
+pub struct Vector3Value(Vector3);
 
-impl CFrameValue {
-    pub fn new(value: CFrame) -> Self {
+impl fmt::Display for Vector3Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.0.x, self.0.y, self.0.z)
+    }
+}
+
+impl Vector3Value {
+    pub fn new(value: Vector3) -> Self {
         Self(value)
     }
 
+    pub fn inner(&self) -> Vector3 {
+        self.0
+    }
+
+    fn magnitude(self) -> f32 {
+        dot(self.0, self.0).sqrt()
+    }
+
+    fn lerp(self, goal: Self, alpha: f32) -> Self {
+        self + (goal - self) * alpha
+    }
+
+    fn fuzzy_eq(self, other: Self, epsilon: f32) -> bool {
+        (self - other).magnitude() <= epsilon
+    }
+
     fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
         match key {
-            "X" => self.0.position.x.to_lua(context),
-            "Y" => self.0.position.y.to_lua(context),
-            "Z" => self.0.position.z.to_lua(context),
-            "RightVector" => Vector3Value::new(Vector3::new(
-                self.0.orientation.x.x,
-                self.0.orientation.y.x,
-                self.0.orientation.z.x,
-            ))
-            .to_lua(context),
-            "UpVector" => Vector3Value::new(Vector3::new(
-                self.0.orientation.x.y,
-                self.0.orientation.y.y,
-                self.0.orientation.z.y,
-            ))
-            .to_lua(context),
-            "LookVector" => Vector3Value::new(Vector3::new(
-                -self.0.orientation.x.z,
-                -self.0.orientation.y.z,
-                -self.0.orientation.z.z,
-            ))
-            .to_lua(context),
-            "XVector" => Vector3Value::new(self.0.orientation.x).to_lua(context),
-            "YVector" => Vector3Value::new(self.0.orientation.y).to_lua(context),
-            "ZVector" => Vector3Value::new(self.0.orientation.z).to_lua(context),
+            "X" => self.0.x.to_lua(context),
+            "Y" => self.0.y.to_lua(context),
+            "Z" => self.0.z.to_lua(context),
+            "Magnitude" => self.magnitude().to_lua(context),
+            "Unit" => Vector3Value::new(unit(self.0)).to_lua(context),
             _ => Err(mlua::Error::external(format!(
-                "'{}' is not a valid member of CFrame",
+                "'{}' is not a valid member of Vector3",
                 key
             ))),
         }
     }
 }
 
+impl ComponentVector for Vector3Value {
+    type Component = f32;
+    const TYPE_NAME: &'static str = "Vector3";
+
+    fn splat(component: f32) -> Self {
+        Vector3Value::new(Vector3::new(component, component, component))
+    }
+
+    fn map(self, f: impl Fn(f32) -> f32) -> Self {
+        Vector3Value::new(Vector3::new(f(self.0.x), f(self.0.y), f(self.0.z)))
+    }
+
+    fn zip(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        Vector3Value::new(Vector3::new(
+            f(self.0.x, other.0.x),
+            f(self.0.y, other.0.y),
+            f(self.0.z, other.0.z),
+        ))
+    }
+}
+
+impl ops::Add for Vector3Value {
+    type Output = Vector3Value;
+    fn add(self, other: Self) -> Self::Output {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+impl ops::Sub for Vector3Value {
+    type Output = Vector3Value;
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+impl ops::Mul for Vector3Value {
+    type Output = Vector3Value;
+    fn mul(self, other: Self) -> Self::Output {
+        self.zip(other, |a, b| a * b)
+    }
+}
+
+impl ops::Mul<f32> for Vector3Value {
+    type Output = Vector3Value;
+    fn mul(self, other: f32) -> Self::Output {
+        self.map(|a| a * other)
+    }
+}
+
+impl ops::Div for Vector3Value {
+    type Output = Vector3Value;
+    fn div(self, other: Self) -> Self::Output {
+        self.zip(other, |a, b| a / b)
+    }
+}
+
+impl ops::Neg for Vector3Value {
+    type Output = Vector3Value;
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl From<&Vector3Value> for Variant {
+    fn from(vector: &Vector3Value) -> Variant {
+        Variant::Vector3(vector.0)
+    }
+}
+
+impl UserData for Vector3Value {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("Dot", |_context, this, other: Self| {
+            Ok(dot(this.0, other.0))
+        });
+        methods.add_method("Cross", |_context, this, other: Self| {
+            Ok(Vector3Value::new(cross(this.0, other.0)))
+        });
+        methods.add_method("Lerp", |_context, this, (goal, alpha): (Self, f32)| {
+            Ok(this.lerp(goal, alpha))
+        });
+        methods.add_method(
+            "FuzzyEq",
+            |_context, this, (other, epsilon): (Self, Option<f32>)| {
+                Ok(this.fuzzy_eq(other, epsilon.unwrap_or(1e-5)))
+            },
+        );
+        methods.add_method("Min", |_context, this, others: Variadic<Self>| {
+            Ok(others
+                .iter()
+                .fold(*this, |min, other| min.zip(*other, f32::min)))
+        });
+        methods.add_method("Max", |_context, this, others: Variadic<Self>| {
+            Ok(others
+                .iter()
+                .fold(*this, |max, other| max.zip(*other, f32::max)))
+        });
+        methods.add_method("Abs", |_context, this, _args: ()| Ok(this.map(f32::abs)));
+        methods.add_method(
+            "Floor",
+            |_context, this, _args: ()| Ok(this.map(f32::floor)),
+        );
+        methods.add_method("Ceil", |_context, this, _args: ()| Ok(this.map(f32::ceil)));
+        methods.add_method("Sign", |_context, this, _args: ()| {
+            Ok(this.map(|a| if a == 0.0 { 0.0 } else { a.signum() }))
+        });
+
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Add, |context, this, rhs: Self| {
+            (*this + rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Self| {
+            (*this - rhs).to_lua(context)
+        });
+        methods.add_meta_function(
+            MetaMethod::Mul,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? * Self::operand(&rhs)?).to_lua(context)
+            },
+        );
+        methods.add_meta_function(
+            MetaMethod::Div,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? / Self::operand(&rhs)?).to_lua(context)
+            },
+        );
+        methods.add_meta_method(MetaMethod::Unm, |context, this, _arg: ()| {
+            (-*this).to_lua(context)
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vector3int16Value(Vector3int16);
+
+impl fmt::Display for Vector3int16Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.0.x, self.0.y, self.0.z)
+    }
+}
+
+impl Vector3int16Value {
+    pub fn new(value: Vector3int16) -> Self {
+        Self(value)
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "X" => self.0.x.to_lua(context),
+            "Y" => self.0.y.to_lua(context),
+            "Z" => self.0.z.to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of Vector3int16",
+                key
+            ))),
+        }
+    }
+}
+
+impl ComponentVector for Vector3int16Value {
+    type Component = i16;
+    const TYPE_NAME: &'static str = "Vector3int16";
+
+    fn splat(component: i16) -> Self {
+        Vector3int16Value::new(Vector3int16::new(component, component, component))
+    }
+
+    fn map(self, f: impl Fn(i16) -> i16) -> Self {
+        Vector3int16Value::new(Vector3int16::new(f(self.0.x), f(self.0.y), f(self.0.z)))
+    }
+
+    fn zip(self, other: Self, f: impl Fn(i16, i16) -> i16) -> Self {
+        Vector3int16Value::new(Vector3int16::new(
+            f(self.0.x, other.0.x),
+            f(self.0.y, other.0.y),
+            f(self.0.z, other.0.z),
+        ))
+    }
+}
+
+impl ops::Add for Vector3int16Value {
+    type Output = Vector3int16Value;
+    fn add(self, other: Self) -> Self::Output {
+        self.zip(other, i16::wrapping_add)
+    }
+}
+
+impl ops::Sub for Vector3int16Value {
+    type Output = Vector3int16Value;
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip(other, i16::wrapping_sub)
+    }
+}
+
+impl ops::Mul for Vector3int16Value {
+    type Output = Vector3int16Value;
+    fn mul(self, other: Self) -> Self::Output {
+        self.zip(other, i16::wrapping_mul)
+    }
+}
+
+impl ops::Div for Vector3int16Value {
+    type Output = LuaResult<Vector3int16Value>;
+    fn div(self, other: Self) -> Self::Output {
+        if other.0.x == 0 || other.0.y == 0 || other.0.z == 0 {
+            return Err(mlua::Error::external("Vector3int16 division by zero"));
+        }
+
+        Ok(self.zip(other, i16::wrapping_div))
+    }
+}
+
+impl ops::Neg for Vector3int16Value {
+    type Output = Vector3int16Value;
+    fn neg(self) -> Self::Output {
+        self.map(i16::wrapping_neg)
+    }
+}
+
+impl From<&Vector3int16Value> for Variant {
+    fn from(color: &Vector3int16Value) -> Variant {
+        Variant::Vector3int16(color.0)
+    }
+}
+
+impl UserData for Vector3int16Value {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Add, |context, this, rhs: Self| {
+            (*this + rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Self| {
+            (*this - rhs).to_lua(context)
+        });
+        methods.add_meta_function(
+            MetaMethod::Mul,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? * Self::operand(&rhs)?).to_lua(context)
+            },
+        );
+        methods.add_meta_function(
+            MetaMethod::Div,
+            |context, (lhs, rhs): (LuaValue, LuaValue)| {
+                (Self::operand(&lhs)? / Self::operand(&rhs)?)?.to_lua(context)
+            },
+        );
+        methods.add_meta_method(MetaMethod::Unm, |context, this, _arg: ()| {
+            (-*this).to_lua(context)
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CFrameValue(CFrame);
+
+impl CFrameValue {
+    pub fn new(value: CFrame) -> Self {
+        Self(value)
+    }
+
+    pub fn from_position(position: Vector3) -> Self {
+        Self(CFrame::new(position, Matrix3::identity()))
+    }
+
+    /// Creates a CFrame from a position and the three column vectors of its
+    /// rotation matrix. Like Roblox, the back vector is derived from the right
+    /// and up vectors if it is not given.
+    pub fn from_matrix(
+        position: Vector3,
+        right: Vector3,
+        up: Vector3,
+        back: Option<Vector3>,
+    ) -> Self {
+        let back = back.unwrap_or_else(|| unit(cross(right, up)));
+
+        Self(CFrame::new(
+            position,
+            Matrix3::new(
+                Vector3::new(right.x, up.x, back.x),
+                Vector3::new(right.y, up.y, back.y),
+                Vector3::new(right.z, up.z, back.z),
+            ),
+        ))
+    }
+
+    /// Creates a CFrame at `position` facing towards `target`, matching
+    /// `CFrame.lookAt`.
+    pub fn look_at(position: Vector3, target: Vector3, up: Vector3) -> Self {
+        let look = unit(sub(target, position));
+        let mut right = cross(look, up);
+
+        // If we're looking straight along the up vector, any right vector is
+        // as good as any other.
+        if dot(right, right) < 1e-10 {
+            right = Vector3::new(1.0, 0.0, 0.0);
+        }
+
+        let right = unit(right);
+        let up = cross(right, look);
+
+        Self::from_matrix(position, right, up, Some(scale(look, -1.0)))
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        let axis = unit(axis);
+        let half_sin = (angle / 2.0).sin();
+
+        Self::from_quaternion(
+            Vector3::new(0.0, 0.0, 0.0),
+            [
+                axis.x * half_sin,
+                axis.y * half_sin,
+                axis.z * half_sin,
+                (angle / 2.0).cos(),
+            ],
+        )
+    }
+
+    /// Creates a rotation that applies rotations around the Z, Y, and X axes,
+    /// in that order.
+    pub fn from_euler_angles_xyz(rx: f32, ry: f32, rz: f32) -> Self {
+        let orientation = mul_matrix(
+            &mul_matrix(&rotation_x(rx), &rotation_y(ry)),
+            &rotation_z(rz),
+        );
+
+        Self(CFrame::new(Vector3::new(0.0, 0.0, 0.0), orientation))
+    }
+
+    /// Creates a rotation that applies rotations around the Z, X, and Y axes,
+    /// in that order.
+    pub fn from_euler_angles_yxz(rx: f32, ry: f32, rz: f32) -> Self {
+        let orientation = mul_matrix(
+            &mul_matrix(&rotation_y(ry), &rotation_x(rx)),
+            &rotation_z(rz),
+        );
+
+        Self(CFrame::new(Vector3::new(0.0, 0.0, 0.0), orientation))
+    }
+
+    /// Creates a CFrame from a position and a quaternion in `[x, y, z, w]`
+    /// order.
+    pub fn from_quaternion(position: Vector3, quaternion: [f32; 4]) -> Self {
+        let length = quaternion.iter().map(|c| c * c).sum::<f32>().sqrt();
+        let [x, y, z, w] = quaternion.map(|c| c / length);
+
+        Self(CFrame::new(
+            position,
+            Matrix3::new(
+                Vector3::new(
+                    1.0 - 2.0 * (y * y + z * z),
+                    2.0 * (x * y - z * w),
+                    2.0 * (x * z + y * w),
+                ),
+                Vector3::new(
+                    2.0 * (x * y + z * w),
+                    1.0 - 2.0 * (x * x + z * z),
+                    2.0 * (y * z - x * w),
+                ),
+                Vector3::new(
+                    2.0 * (x * z - y * w),
+                    2.0 * (y * z + x * w),
+                    1.0 - 2.0 * (x * x + y * y),
+                ),
+            ),
+        ))
+    }
+
+    /// Creates a CFrame from the 12 numbers returned by `GetComponents`.
+    pub fn from_components(components: [f32; 12]) -> Self {
+        let [x, y, z, r00, r01, r02, r10, r11, r12, r20, r21, r22] = components;
+
+        Self(CFrame::new(
+            Vector3::new(x, y, z),
+            Matrix3::new(
+                Vector3::new(r00, r01, r02),
+                Vector3::new(r10, r11, r12),
+                Vector3::new(r20, r21, r22),
+            ),
+        ))
+    }
+
+    pub fn components(&self) -> [f32; 12] {
+        let position = self.0.position;
+        let Matrix3 { x, y, z } = self.0.orientation;
+
+        [
+            position.x, position.y, position.z, x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z,
+        ]
+    }
+
+    pub fn inverse(&self) -> Self {
+        let orientation = self.0.orientation.transpose();
+        let position = scale(mul_vector(&orientation, self.0.position), -1.0);
+
+        Self(CFrame::new(position, orientation))
+    }
+
+    pub fn lerp(&self, goal: Self, alpha: f32) -> Self {
+        let position = add(
+            self.0.position,
+            scale(sub(goal.0.position, self.0.position), alpha),
+        );
+
+        let from = to_quaternion(&self.0.orientation);
+        let mut to = to_quaternion(&goal.0.orientation);
+
+        // Take the shortest path between the two rotations.
+        let mut cos_theta: f32 = from.iter().zip(&to).map(|(a, b)| a * b).sum();
+        if cos_theta < 0.0 {
+            to = to.map(|c| -c);
+            cos_theta = -cos_theta;
+        }
+
+        let (from_weight, to_weight) = if cos_theta > 0.9999 {
+            (1.0 - alpha, alpha)
+        } else {
+            let theta = cos_theta.acos();
+            let sin_theta = theta.sin();
+
+            (
+                ((1.0 - alpha) * theta).sin() / sin_theta,
+                (alpha * theta).sin() / sin_theta,
+            )
+        };
+
+        let mut quaternion = [0.0; 4];
+        for i in 0..4 {
+            quaternion[i] = from[i] * from_weight + to[i] * to_weight;
+        }
+
+        Self::from_quaternion(position, quaternion)
+    }
+
+    pub fn point_to_world_space(&self, point: Vector3) -> Vector3 {
+        add(mul_vector(&self.0.orientation, point), self.0.position)
+    }
+
+    pub fn point_to_object_space(&self, point: Vector3) -> Vector3 {
+        self.inverse().point_to_world_space(point)
+    }
+
+    pub fn vector_to_world_space(&self, vector: Vector3) -> Vector3 {
+        mul_vector(&self.0.orientation, vector)
+    }
+
+    pub fn vector_to_object_space(&self, vector: Vector3) -> Vector3 {
+        mul_vector(&self.0.orientation.transpose(), vector)
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "X" => self.0.position.x.to_lua(context),
+            "Y" => self.0.position.y.to_lua(context),
+            "Z" => self.0.position.z.to_lua(context),
+            "Position" | "p" => Vector3Value::new(self.0.position).to_lua(context),
+            "Rotation" => {
+                CFrameValue::new(CFrame::new(Vector3::new(0.0, 0.0, 0.0), self.0.orientation))
+                    .to_lua(context)
+            }
+            "RightVector" | "XVector" => Vector3Value::new(Vector3::new(
+                self.0.orientation.x.x,
+                self.0.orientation.y.x,
+                self.0.orientation.z.x,
+            ))
+            .to_lua(context),
+            "UpVector" | "YVector" => Vector3Value::new(Vector3::new(
+                self.0.orientation.x.y,
+                self.0.orientation.y.y,
+                self.0.orientation.z.y,
+            ))
+            .to_lua(context),
+            "LookVector" => Vector3Value::new(Vector3::new(
+                -self.0.orientation.x.z,
+                -self.0.orientation.y.z,
+                -self.0.orientation.z.z,
+            ))
+            .to_lua(context),
+            "ZVector" => Vector3Value::new(Vector3::new(
+                self.0.orientation.x.z,
+                self.0.orientation.y.z,
+                self.0.orientation.z.z,
+            ))
+            .to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of CFrame",
+                key
+            ))),
+        }
+    }
+
+    fn meta_mul<'lua>(
+        &self,
+        context: &'lua Lua,
+        rhs: LuaValue<'lua>,
+    ) -> mlua::Result<mlua::Value<'lua>> {
+        if let LuaValue::UserData(user_data) = &rhs {
+            if let Ok(cframe) = user_data.borrow::<CFrameValue>() {
+                return (*self * *cframe).to_lua(context);
+            }
+
+            if let Ok(vector) = user_data.borrow::<Vector3Value>() {
+                return Vector3Value::new(self.point_to_world_space(vector.inner()))
+                    .to_lua(context);
+            }
+        }
+
+        Err(mlua::Error::external(
+            "CFrames can only be multiplied by a CFrame or Vector3",
+        ))
+    }
+}
+
+impl ops::Mul for CFrameValue {
+    type Output = CFrameValue;
+    fn mul(self, other: Self) -> Self::Output {
+        CFrameValue::new(CFrame::new(
+            self.point_to_world_space(other.0.position),
+            mul_matrix(&self.0.orientation, &other.0.orientation),
+        ))
+    }
+}
+
+impl ops::Add<Vector3Value> for CFrameValue {
+    type Output = CFrameValue;
+    fn add(self, other: Vector3Value) -> Self::Output {
+        CFrameValue::new(CFrame::new(
+            add(self.0.position, other.0),
+            self.0.orientation,
+        ))
+    }
+}
+
+impl ops::Sub<Vector3Value> for CFrameValue {
+    type Output = CFrameValue;
+    fn sub(self, other: Vector3Value) -> Self::Output {
+        CFrameValue::new(CFrame::new(
+            sub(self.0.position, other.0),
+            self.0.orientation,
+        ))
+    }
+}
+
 impl From<&CFrameValue> for Variant {
     fn from(cframe: &CFrameValue) -> Variant {
         Variant::CFrame(cframe.0)
     }
 }
 
-impl fmt::Display for CFrameValue {
+impl fmt::Display for CFrameValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = self
+            .components()
+            .iter()
+            .map(|component| component.to_string())
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", components.join(", "))
+    }
+}
+
+impl UserData for CFrameValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("Inverse", |_context, this, _args: ()| Ok(this.inverse()));
+        methods.add_method("Lerp", |_context, this, (goal, alpha): (Self, f32)| {
+            Ok(this.lerp(goal, alpha))
+        });
+        methods.add_method("ToWorldSpace", |_context, this, cframe: Self| {
+            Ok(*this * cframe)
+        });
+        methods.add_method("ToObjectSpace", |_context, this, cframe: Self| {
+            Ok(this.inverse() * cframe)
+        });
+        methods.add_method(
+            "PointToWorldSpace",
+            |_context, this, point: Vector3Value| {
+                Ok(Vector3Value::new(this.point_to_world_space(point.0)))
+            },
+        );
+        methods.add_method(
+            "PointToObjectSpace",
+            |_context, this, point: Vector3Value| {
+                Ok(Vector3Value::new(this.point_to_object_space(point.0)))
+            },
+        );
+        methods.add_method(
+            "VectorToWorldSpace",
+            |_context, this, vector: Vector3Value| {
+                Ok(Vector3Value::new(this.vector_to_world_space(vector.0)))
+            },
+        );
+        methods.add_method(
+            "VectorToObjectSpace",
+            |_context, this, vector: Vector3Value| {
+                Ok(Vector3Value::new(this.vector_to_object_space(vector.0)))
+            },
+        );
+        methods.add_method("GetComponents", |_context, this, _args: ()| {
+            Ok(Variadic::from_iter(this.components()))
+        });
+
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Mul, |context, this, rhs: LuaValue| {
+            this.meta_mul(context, rhs)
+        });
+        methods.add_meta_method(MetaMethod::Add, |context, this, rhs: Vector3Value| {
+            (*this + rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Vector3Value| {
+            (*this - rhs).to_lua(context)
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+fn add(a: Vector3, b: Vector3) -> Vector3 {
+    Vector3::new(a.x + b.x, a.y + b.y, a.z + b.z)
+}
+
+fn sub(a: Vector3, b: Vector3) -> Vector3 {
+    Vector3::new(a.x - b.x, a.y - b.y, a.z - b.z)
+}
+
+fn scale(a: Vector3, factor: f32) -> Vector3 {
+    Vector3::new(a.x * factor, a.y * factor, a.z * factor)
+}
+
+fn dot(a: Vector3, b: Vector3) -> f32 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+fn cross(a: Vector3, b: Vector3) -> Vector3 {
+    Vector3::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
+
+/// Like in Roblox, the unit vector of a zero vector is made of NaNs.
+fn unit(a: Vector3) -> Vector3 {
+    let length = dot(a, a).sqrt();
+    Vector3::new(a.x / length, a.y / length, a.z / length)
+}
+
+/// Multiplies a rotation matrix, stored as rows, by a column vector.
+fn mul_vector(matrix: &Matrix3, vector: Vector3) -> Vector3 {
+    Vector3::new(
+        dot(matrix.x, vector),
+        dot(matrix.y, vector),
+        dot(matrix.z, vector),
+    )
+}
+
+fn mul_matrix(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let row = |row: Vector3| add(add(scale(b.x, row.x), scale(b.y, row.y)), scale(b.z, row.z));
+
+    Matrix3::new(row(a.x), row(a.y), row(a.z))
+}
+
+fn rotation_x(angle: f32) -> Matrix3 {
+    let (sin, cos) = angle.sin_cos();
+
+    Matrix3::new(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, cos, -sin),
+        Vector3::new(0.0, sin, cos),
+    )
+}
+
+fn rotation_y(angle: f32) -> Matrix3 {
+    let (sin, cos) = angle.sin_cos();
+
+    Matrix3::new(
+        Vector3::new(cos, 0.0, sin),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(-sin, 0.0, cos),
+    )
+}
+
+fn rotation_z(angle: f32) -> Matrix3 {
+    let (sin, cos) = angle.sin_cos();
+
+    Matrix3::new(
+        Vector3::new(cos, -sin, 0.0),
+        Vector3::new(sin, cos, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    )
+}
+
+/// Converts a rotation matrix into a quaternion in `[x, y, z, w]` order.
+fn to_quaternion(matrix: &Matrix3) -> [f32; 4] {
+    let (m00, m01, m02) = (matrix.x.x, matrix.x.y, matrix.x.z);
+    let (m10, m11, m12) = (matrix.y.x, matrix.y.y, matrix.y.z);
+    let (m20, m21, m22) = (matrix.z.x, matrix.z.y, matrix.z.z);
+
+    let trace = m00 + m11 + m22;
+
+    if trace > 0.0 {
+        let s = 0.5 / (trace + 1.0).sqrt();
+        [(m21 - m12) * s, (m02 - m20) * s, (m10 - m01) * s, 0.25 / s]
+    } else if m00 > m11 && m00 > m22 {
+        let s = 2.0 * (1.0 + m00 - m11 - m22).sqrt();
+        [0.25 * s, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s]
+    } else if m11 > m22 {
+        let s = 2.0 * (1.0 + m11 - m00 - m22).sqrt();
+        [(m01 + m10) / s, 0.25 * s, (m12 + m21) / s, (m02 - m20) / s]
+    } else {
+        let s = 2.0 * (1.0 + m22 - m00 - m11).sqrt();
+        [(m02 + m20) / s, (m12 + m21) / s, 0.25 * s, (m10 - m01) / s]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UDimValue(UDim);
+
+impl fmt::Display for UDimValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.0.scale, self.0.offset)
+    }
+}
+
+impl UDimValue {
+    pub fn new(value: UDim) -> Self {
+        Self(value)
+    }
+
+    pub fn inner(&self) -> UDim {
+        self.0
+    }
+
+    fn lerp(self, goal: Self, alpha: f32) -> Self {
+        UDimValue::new(UDim::new(
+            self.0.scale + (goal.0.scale - self.0.scale) * alpha,
+            self.0.offset + ((goal.0.offset - self.0.offset) as f32 * alpha).round() as i32,
+        ))
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Scale" => self.0.scale.to_lua(context),
+            "Offset" => self.0.offset.to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of UDim",
+                key
+            ))),
+        }
+    }
+}
+
+impl ops::Add for UDimValue {
+    type Output = UDimValue;
+    fn add(self, other: Self) -> Self::Output {
+        UDimValue::new(UDim::new(
+            self.0.scale + other.0.scale,
+            self.0.offset + other.0.offset,
+        ))
+    }
+}
+
+impl ops::Sub for UDimValue {
+    type Output = UDimValue;
+    fn sub(self, other: Self) -> Self::Output {
+        UDimValue::new(UDim::new(
+            self.0.scale - other.0.scale,
+            self.0.offset - other.0.offset,
+        ))
+    }
+}
+
+impl ops::Neg for UDimValue {
+    type Output = UDimValue;
+    fn neg(self) -> Self::Output {
+        UDimValue::new(UDim::new(-self.0.scale, -self.0.offset))
+    }
+}
+
+impl From<&UDimValue> for Variant {
+    fn from(udim: &UDimValue) -> Variant {
+        Variant::UDim(udim.0)
+    }
+}
+
+impl UserData for UDimValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Add, |context, this, rhs: Self| {
+            (*this + rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Self| {
+            (*this - rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Unm, |context, this, _arg: ()| {
+            (-*this).to_lua(context)
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UDim2Value(UDim2);
+
+impl fmt::Display for UDim2Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{{}, {}}}, {{{}, {}}}",
+            self.0.x.scale, self.0.x.offset, self.0.y.scale, self.0.y.offset
+        )
+    }
+}
+
+impl UDim2Value {
+    pub fn new(value: UDim2) -> Self {
+        Self(value)
+    }
+
+    fn lerp(self, goal: Self, alpha: f32) -> Self {
+        UDim2Value::new(UDim2::new(
+            UDimValue::new(self.0.x)
+                .lerp(UDimValue::new(goal.0.x), alpha)
+                .inner(),
+            UDimValue::new(self.0.y)
+                .lerp(UDimValue::new(goal.0.y), alpha)
+                .inner(),
+        ))
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "X" | "Width" => UDimValue::new(self.0.x).to_lua(context),
+            "Y" | "Height" => UDimValue::new(self.0.y).to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of UDim2",
+                key
+            ))),
+        }
+    }
+}
+
+impl ops::Add for UDim2Value {
+    type Output = UDim2Value;
+    fn add(self, other: Self) -> Self::Output {
+        UDim2Value::new(UDim2::new(
+            (UDimValue::new(self.0.x) + UDimValue::new(other.0.x)).inner(),
+            (UDimValue::new(self.0.y) + UDimValue::new(other.0.y)).inner(),
+        ))
+    }
+}
+
+impl ops::Sub for UDim2Value {
+    type Output = UDim2Value;
+    fn sub(self, other: Self) -> Self::Output {
+        UDim2Value::new(UDim2::new(
+            (UDimValue::new(self.0.x) - UDimValue::new(other.0.x)).inner(),
+            (UDimValue::new(self.0.y) - UDimValue::new(other.0.y)).inner(),
+        ))
+    }
+}
+
+impl ops::Neg for UDim2Value {
+    type Output = UDim2Value;
+    fn neg(self) -> Self::Output {
+        UDim2Value::new(UDim2::new(
+            (-UDimValue::new(self.0.x)).inner(),
+            (-UDimValue::new(self.0.y)).inner(),
+        ))
+    }
+}
+
+impl From<&UDim2Value> for Variant {
+    fn from(udim2: &UDim2Value) -> Variant {
+        Variant::UDim2(udim2.0)
+    }
+}
+
+impl UserData for UDim2Value {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("Lerp", |_context, this, (goal, alpha): (Self, f32)| {
+            Ok(this.lerp(goal, alpha))
+        });
+
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Add, |context, this, rhs: Self| {
+            (*this + rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Sub, |context, this, rhs: Self| {
+            (*this - rhs).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Unm, |context, this, _arg: ()| {
+            (-*this).to_lua(context)
+        });
+
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NumberRangeValue(NumberRange);
+
+impl fmt::Display for NumberRangeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.0.min, self.0.max)
+    }
+}
+
+impl NumberRangeValue {
+    pub fn new(value: NumberRange) -> Self {
+        Self(value)
+    }
+
+    pub fn from_min_max(min: f32, max: f32) -> LuaResult<Self> {
+        if max < min {
+            return Err(mlua::Error::external(
+                "NumberRange's maximum cannot be less than its minimum",
+            ));
+        }
+
+        Ok(Self(NumberRange::new(min, max)))
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Min" => self.0.min.to_lua(context),
+            "Max" => self.0.max.to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of NumberRange",
+                key
+            ))),
+        }
+    }
+}
+
+impl From<&NumberRangeValue> for Variant {
+    fn from(range: &NumberRangeValue) -> Variant {
+        Variant::NumberRange(range.0)
+    }
+}
+
+impl UserData for NumberRangeValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+/// Checks the rules Roblox enforces on the keypoints of ColorSequence and
+/// NumberSequence values.
+fn validate_keypoint_times(name: &str, times: &[f32]) -> LuaResult<()> {
+    if times.len() < 2 {
+        return Err(mlua::Error::external(format!(
+            "{} requires at least 2 keypoints",
+            name
+        )));
+    }
+
+    if times[0] != 0.0 || times[times.len() - 1] != 1.0 {
+        return Err(mlua::Error::external(format!(
+            "{} must start at time 0 and end at time 1",
+            name
+        )));
+    }
+
+    if times.windows(2).any(|pair| pair[1] < pair[0]) {
+        return Err(mlua::Error::external(format!(
+            "{} keypoints must be ordered by time",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorSequenceKeypointValue(ColorSequenceKeypoint);
+
+impl fmt::Display for ColorSequenceKeypointValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}",
-            self.0.position.x,
-            self.0.position.y,
-            self.0.position.z,
-            self.0.orientation.x.x,
-            self.0.orientation.y.x,
-            self.0.orientation.z.x,
-            self.0.orientation.x.y,
-            self.0.orientation.y.y,
-            self.0.orientation.z.y,
-            self.0.orientation.x.z,
-            self.0.orientation.y.z,
-            self.0.orientation.z.z,
+            "{} {} {} {} 0",
+            self.0.time, self.0.color.r, self.0.color.g, self.0.color.b
         )
     }
 }
 
-impl UserData for CFrameValue {
+impl ColorSequenceKeypointValue {
+    pub fn new(value: ColorSequenceKeypoint) -> Self {
+        Self(value)
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Time" => self.0.time.to_lua(context),
+            "Value" => Color3Value::new(self.0.color).to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of ColorSequenceKeypoint",
+                key
+            ))),
+        }
+    }
+}
+
+impl UserData for ColorSequenceKeypointValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ColorSequenceValue(ColorSequence);
+
+impl fmt::Display for ColorSequenceValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keypoints = self
+            .0
+            .keypoints
+            .iter()
+            .map(|keypoint| ColorSequenceKeypointValue::new(*keypoint).to_string())
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", keypoints.join(" "))
+    }
+}
+
+impl ColorSequenceValue {
+    pub fn new(value: ColorSequence) -> Self {
+        Self(value)
+    }
+
+    pub fn from_keypoints(keypoints: Vec<ColorSequenceKeypointValue>) -> LuaResult<Self> {
+        let times: Vec<_> = keypoints.iter().map(|keypoint| keypoint.0.time).collect();
+        validate_keypoint_times("ColorSequence", &times)?;
+
+        Ok(Self(ColorSequence {
+            keypoints: keypoints.into_iter().map(|keypoint| keypoint.0).collect(),
+        }))
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Keypoints" => self
+                .0
+                .keypoints
+                .iter()
+                .map(|keypoint| ColorSequenceKeypointValue::new(*keypoint))
+                .collect::<Vec<_>>()
+                .to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of ColorSequence",
+                key
+            ))),
+        }
+    }
+}
+
+impl From<&ColorSequenceValue> for Variant {
+    fn from(sequence: &ColorSequenceValue) -> Variant {
+        Variant::ColorSequence(sequence.0.clone())
+    }
+}
+
+impl UserData for ColorSequenceValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NumberSequenceKeypointValue(NumberSequenceKeypoint);
+
+impl fmt::Display for NumberSequenceKeypointValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.0.time, self.0.value, self.0.envelope)
+    }
+}
+
+impl NumberSequenceKeypointValue {
+    pub fn new(value: NumberSequenceKeypoint) -> Self {
+        Self(value)
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Time" => self.0.time.to_lua(context),
+            "Value" => self.0.value.to_lua(context),
+            "Envelope" => self.0.envelope.to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of NumberSequenceKeypoint",
+                key
+            ))),
+        }
+    }
+}
+
+impl UserData for NumberSequenceKeypointValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.0 == rhs.0).to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NumberSequenceValue(NumberSequence);
+
+impl fmt::Display for NumberSequenceValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keypoints = self
+            .0
+            .keypoints
+            .iter()
+            .map(|keypoint| NumberSequenceKeypointValue::new(*keypoint).to_string())
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", keypoints.join(" "))
+    }
+}
+
+impl NumberSequenceValue {
+    pub fn new(value: NumberSequence) -> Self {
+        Self(value)
+    }
+
+    pub fn from_keypoints(keypoints: Vec<NumberSequenceKeypointValue>) -> LuaResult<Self> {
+        let times: Vec<_> = keypoints.iter().map(|keypoint| keypoint.0.time).collect();
+        validate_keypoint_times("NumberSequence", &times)?;
+
+        Ok(Self(NumberSequence {
+            keypoints: keypoints.into_iter().map(|keypoint| keypoint.0).collect(),
+        }))
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Keypoints" => self
+                .0
+                .keypoints
+                .iter()
+                .map(|keypoint| NumberSequenceKeypointValue::new(*keypoint))
+                .collect::<Vec<_>>()
+                .to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of NumberSequence",
+                key
+            ))),
+        }
+    }
+}
+
+impl From<&NumberSequenceValue> for Variant {
+    fn from(sequence: &NumberSequenceValue) -> Variant {
+        Variant::NumberSequence(sequence.0.clone())
+    }
+}
+
+impl UserData for NumberSequenceValue {
     fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
         methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
             (this.0 == rhs.0).to_lua(context)
         });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EnumValue(&'static EnumDescriptor<'static>);
+
+impl fmt::Display for EnumValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.name)
+    }
+}
+
+impl EnumValue {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let database = rbx_reflection_database::get();
+
+        database.enums.get(name).map(Self)
+    }
+
+    pub fn name(&self) -> &'static str {
+        &self.0.name
+    }
+
+    pub fn item(&self, name: &str) -> Option<EnumItemValue> {
+        let (name, value) = self.0.items.get_key_value(name)?;
+
+        Some(EnumItemValue {
+            enum_type: *self,
+            name,
+            value: *value,
+        })
+    }
+
+    pub fn item_from_value(&self, value: u32) -> Option<EnumItemValue> {
+        self.items().into_iter().find(|item| item.value == value)
+    }
+
+    /// Returns all of the items in this enum, ordered by their value.
+    pub fn items(&self) -> Vec<EnumItemValue> {
+        let mut items: Vec<_> = self
+            .0
+            .items
+            .iter()
+            .map(|(name, value)| EnumItemValue {
+                enum_type: *self,
+                name,
+                value: *value,
+            })
+            .collect();
+
+        items.sort_by_key(|item| (item.value, item.name));
+        items
+    }
+
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match self.item(key) {
+            Some(item) => item.to_lua(context),
+            None => Err(mlua::Error::external(format!(
+                "'{}' is not a valid EnumItem of {}",
+                key, self.0.name
+            ))),
+        }
+    }
+}
+
+impl UserData for EnumValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("GetEnumItems", |_context, this, _args: ()| Ok(this.items()));
 
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.name() == rhs.name()).to_lua(context)
+        });
         methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
             this.meta_index(context, &key)
         });
+        methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
+            this.to_string().to_lua(context)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EnumItemValue {
+    enum_type: EnumValue,
+    name: &'static str,
+    value: u32,
+}
+
+impl fmt::Display for EnumItemValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Enum.{}.{}", self.enum_type, self.name)
+    }
+}
+
+impl EnumItemValue {
+    fn meta_index<'lua>(&self, context: &'lua Lua, key: &str) -> mlua::Result<mlua::Value<'lua>> {
+        match key {
+            "Name" => self.name.to_lua(context),
+            "Value" => self.value.to_lua(context),
+            "EnumType" => self.enum_type.to_lua(context),
+            _ => Err(mlua::Error::external(format!(
+                "'{}' is not a valid member of EnumItem",
+                key
+            ))),
+        }
+    }
+}
+
+impl From<&EnumItemValue> for Variant {
+    fn from(item: &EnumItemValue) -> Variant {
+        Variant::Enum(Enum::from_u32(item.value))
+    }
+}
 
+impl UserData for EnumItemValue {
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_meta_method(MetaMethod::Eq, |context, this, rhs: Self| {
+            (this.enum_type.name() == rhs.enum_type.name() && this.value == rhs.value)
+                .to_lua(context)
+        });
+        methods.add_meta_method(MetaMethod::Index, |context, this, key: String| {
+            this.meta_index(context, &key)
+        });
         methods.add_meta_method(MetaMethod::ToString, |context, this, _arg: ()| {
             this.to_string().to_lua(context)
         });
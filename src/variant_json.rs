@@ -0,0 +1,75 @@
+//! Conversions from Roblox values to JSON, shared by everything that writes
+//! values out as text.
+
+use rbx_dom_weak::types::Variant;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+/// Turns a value into JSON the same way Rojo writes it in project and
+/// `.meta.json` files. Returns `None` for types that Rojo does not support.
+pub fn variant_to_json(value: &Variant) -> Option<JsonValue> {
+    let json = match value {
+        Variant::Bool(value) => JsonValue::Bool(*value),
+        Variant::Float32(value) => float_to_json(*value)?,
+        Variant::Float64(value) => JsonValue::Number(serde_json::Number::from_f64(*value)?),
+        Variant::Int32(value) => JsonValue::from(*value),
+        Variant::Int64(value) => JsonValue::from(*value),
+        Variant::String(value) => JsonValue::String(value.clone()),
+        Variant::Content(value) => JsonValue::String(AsRef::<str>::as_ref(value).to_owned()),
+        Variant::Enum(value) => JsonValue::from(value.to_u32()),
+        Variant::Vector2(value) => floats_to_json(&[value.x, value.y])?,
+        Variant::Vector2int16(value) => JsonValue::from(vec![value.x, value.y]),
+        Variant::Vector3(value) => floats_to_json(&[value.x, value.y, value.z])?,
+        Variant::Vector3int16(value) => JsonValue::from(vec![value.x, value.y, value.z]),
+        Variant::Color3(value) => floats_to_json(&[value.r, value.g, value.b])?,
+        Variant::Color3uint8(value) => JsonValue::from(vec![value.r, value.g, value.b]),
+        Variant::UDim(value) => JsonValue::Array(vec![
+            float_to_json(value.scale)?,
+            JsonValue::from(value.offset),
+        ]),
+        Variant::UDim2(value) => JsonValue::Array(vec![
+            variant_to_json(&Variant::UDim(value.x))?,
+            variant_to_json(&Variant::UDim(value.y))?,
+        ]),
+        Variant::NumberRange(value) => floats_to_json(&[value.min, value.max])?,
+        Variant::BrickColor(value) => JsonValue::from(*value as u16),
+        Variant::CFrame(value) => {
+            let position = value.position;
+            let orientation = value.orientation;
+            let mut object = JsonMap::new();
+
+            object.insert(
+                "position".to_owned(),
+                floats_to_json(&[position.x, position.y, position.z])?,
+            );
+            object.insert(
+                "orientation".to_owned(),
+                JsonValue::Array(vec![
+                    floats_to_json(&[orientation.x.x, orientation.x.y, orientation.x.z])?,
+                    floats_to_json(&[orientation.y.x, orientation.y.y, orientation.y.z])?,
+                    floats_to_json(&[orientation.z.x, orientation.z.y, orientation.z.z])?,
+                ]),
+            );
+
+            JsonValue::Object(object)
+        }
+        Variant::Tags(tags) => JsonValue::from(tags.iter().collect::<Vec<_>>()),
+        _ => return None,
+    };
+
+    Some(json)
+}
+
+/// Goes through the shortest decimal representation of the float, so that
+/// values like `0.1` don't turn into `0.10000000149011612`.
+fn float_to_json(value: f32) -> Option<JsonValue> {
+    let value: f64 = value.to_string().parse().ok()?;
+    serde_json::Number::from_f64(value).map(JsonValue::Number)
+}
+
+fn floats_to_json(values: &[f32]) -> Option<JsonValue> {
+    values
+        .iter()
+        .map(|value| float_to_json(*value))
+        .collect::<Option<Vec<_>>>()
+        .map(JsonValue::Array)
+}
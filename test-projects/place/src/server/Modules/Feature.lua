@@ -0,0 +1 @@
+return true
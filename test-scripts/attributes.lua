@@ -0,0 +1,43 @@
+local folder = Instance.new("Folder")
+
+assert(folder:GetAttribute("Version") == nil)
+assert(next(folder:GetAttributes()) == nil)
+
+folder:SetAttribute("Version", "1.2.3")
+folder:SetAttribute("Build", 42)
+folder:SetAttribute("Release", true)
+folder:SetAttribute("Offset", Vector3.new(1, 2, 3))
+folder:SetAttribute("Tint", Color3.new(1, 0, 0))
+folder:SetAttribute("Size", UDim2.new(1, 0, 0, 10))
+folder:SetAttribute("Range", NumberRange.new(1, 2))
+
+assert(folder:GetAttribute("Version") == "1.2.3")
+assert(folder:GetAttribute("Build") == 42)
+assert(folder:GetAttribute("Release") == true)
+assert(folder:GetAttribute("Offset") == Vector3.new(1, 2, 3))
+assert(folder:GetAttribute("Tint") == Color3.new(1, 0, 0))
+assert(folder:GetAttribute("Size") == UDim2.new(1, 0, 0, 10))
+assert(folder:GetAttribute("Range") == NumberRange.new(1, 2))
+
+folder:SetAttribute("Release", nil)
+assert(folder:GetAttribute("Release") == nil)
+
+local attributes = folder:GetAttributes()
+assert(attributes.Version == "1.2.3")
+assert(attributes.Build == 42)
+assert(attributes.Release == nil)
+
+assert(not pcall(folder.SetAttribute, folder, "Not valid", 1))
+assert(not pcall(folder.SetAttribute, folder, "RBXReserved", 1))
+assert(not pcall(folder.SetAttribute, folder, "Instance", folder))
+
+-- Attributes should survive being written to and read from both formats.
+remodel.writeModelFile("temp/attributes.rbxm", folder)
+remodel.writeModelFile("temp/attributes.rbxmx", folder)
+
+for _, path in ipairs({ "temp/attributes.rbxm", "temp/attributes.rbxmx" }) do
+	local copy = remodel.readModelFile(path)[1]
+	assert(copy:GetAttribute("Version") == "1.2.3", path)
+	assert(copy:GetAttribute("Build") == 42, path)
+	assert(copy:GetAttribute("Offset") == Vector3.new(1, 2, 3), path)
+end
@@ -0,0 +1,82 @@
+local EPSILON = 1e-4
+
+local function assertFuzzy(a, b, message)
+	assert(math.abs(a - b) < EPSILON, ("%s: %f ~= %f"):format(message or "value", a, b))
+end
+
+local function assertVector(vec, x, y, z)
+	assertFuzzy(vec.X, x, "x")
+	assertFuzzy(vec.Y, y, "y")
+	assertFuzzy(vec.Z, z, "z")
+end
+
+local function assertCFrame(a, b)
+	local ac = { a:GetComponents() }
+	local bc = { b:GetComponents() }
+
+	for i = 1, 12 do
+		assertFuzzy(ac[i], bc[i], ("component %d of %s vs %s"):format(i, tostring(a), tostring(b)))
+	end
+end
+
+-- 12-number constructor and GetComponents round-trip
+local components = { 1, 2, 3, 0, -1, 0, 1, 0, 0, 0, 0, 1 }
+local cframe = CFrame.new(table.unpack(components))
+local gotComponents = { cframe:GetComponents() }
+for i = 1, 12 do
+	assert(gotComponents[i] == components[i])
+end
+assert(tostring(cframe) == "1, 2, 3, 0, -1, 0, 1, 0, 0, 0, 0, 1")
+assertVector(cframe.Position, 1, 2, 3)
+assertVector(cframe.RightVector, 0, 1, 0)
+assertVector(cframe.XVector, 0, 1, 0)
+
+-- Multiplication
+local moved = CFrame.new(1, 2, 3) * CFrame.new(4, 5, 6)
+assertVector(moved.Position, 5, 7, 9)
+
+local rotated = CFrame.Angles(0, math.pi / 2, 0)
+assertVector(rotated * Vector3.new(1, 0, 0), 0, 0, -1)
+assertVector(rotated.LookVector, -1, 0, 0)
+assertVector((CFrame.new(10, 0, 0) * rotated) * Vector3.new(0, 0, -1), 9, 0, 0)
+
+assertVector((CFrame.new(1, 2, 3) + Vector3.new(1, 1, 1)).Position, 2, 3, 4)
+assertVector((CFrame.new(1, 2, 3) - Vector3.new(1, 1, 1)).Position, 0, 1, 2)
+
+-- Inverse
+local complex = CFrame.new(1, 2, 3) * CFrame.Angles(0.3, 0.5, 0.7)
+assertCFrame(complex * complex:Inverse(), CFrame.new())
+assertCFrame(complex:ToObjectSpace(complex), CFrame.new())
+assertCFrame(complex:ToWorldSpace(CFrame.new()), complex)
+assertVector(complex:PointToObjectSpace(complex:PointToWorldSpace(Vector3.new(4, 5, 6))), 4, 5, 6)
+assertVector(complex:VectorToObjectSpace(complex:VectorToWorldSpace(Vector3.new(4, 5, 6))), 4, 5, 6)
+assertVector(CFrame.new(1, 2, 3):VectorToWorldSpace(Vector3.new(1, 0, 0)), 1, 0, 0)
+
+-- Angle constructors
+assertCFrame(CFrame.Angles(0.1, 0.2, 0.3), CFrame.fromEulerAnglesXYZ(0.1, 0.2, 0.3))
+assertCFrame(
+	CFrame.fromEulerAnglesYXZ(0.1, 0.2, 0.3),
+	CFrame.Angles(0, 0.2, 0) * CFrame.Angles(0.1, 0, 0) * CFrame.Angles(0, 0, 0.3)
+)
+assertCFrame(CFrame.fromOrientation(0.1, 0.2, 0.3), CFrame.fromEulerAnglesYXZ(0.1, 0.2, 0.3))
+assertCFrame(CFrame.fromAxisAngle(Vector3.new(0, 1, 0), math.pi / 2), rotated)
+assertCFrame(CFrame.fromAxisAngle(Vector3.new(0, 5, 0), 0.4), CFrame.Angles(0, 0.4, 0))
+
+-- lookAt
+local lookAt = CFrame.lookAt(Vector3.new(0, 0, 0), Vector3.new(10, 0, 0))
+assertVector(lookAt.LookVector, 1, 0, 0)
+assertVector(lookAt.UpVector, 0, 1, 0)
+assertCFrame(CFrame.new(Vector3.new(0, 0, 0), Vector3.new(10, 0, 0)), lookAt)
+
+-- fromMatrix
+assertCFrame(
+	CFrame.fromMatrix(Vector3.new(1, 2, 3), Vector3.new(0, 1, 0), Vector3.new(-1, 0, 0)),
+	CFrame.new(1, 2, 3, 0, -1, 0, 1, 0, 0, 0, 0, 1)
+)
+
+-- Lerp
+local from = CFrame.new(0, 0, 0)
+local to = CFrame.new(10, 0, 0) * CFrame.Angles(0, math.pi / 2, 0)
+assertCFrame(from:Lerp(to, 0), from)
+assertCFrame(from:Lerp(to, 1), to)
+assertCFrame(from:Lerp(to, 0.5), CFrame.new(5, 0, 0) * CFrame.Angles(0, math.pi / 4, 0))
@@ -0,0 +1,43 @@
+local game = Instance.new("DataModel")
+local workspace = game:GetService("Workspace")
+
+local model = Instance.new("Model")
+model.Name = "Car"
+model.Parent = workspace
+
+local folder = Instance.new("Folder")
+folder.Parent = model
+
+local wedge = Instance.new("WedgePart")
+wedge.Name = "Wheel"
+wedge.Parent = folder
+
+assert(wedge:IsA("WedgePart"))
+assert(wedge:IsA("BasePart"))
+assert(wedge:IsA("Instance"))
+assert(not wedge:IsA("Model"))
+assert(not wedge:IsA("NotAClass"))
+assert(workspace:IsA("Model"))
+
+assert(model:FindFirstChild("Wheel") == nil)
+assert(model:FindFirstChild("Wheel", true) == wedge)
+assert(model:FindFirstChildWhichIsA("BasePart") == nil)
+assert(model:FindFirstChildWhichIsA("BasePart", true) == wedge)
+assert(folder:FindFirstChildWhichIsA("PVInstance") == wedge)
+assert(folder:FindFirstChildOfClass("BasePart") == nil)
+
+assert(wedge:FindFirstAncestor("Car") == model)
+assert(wedge:FindFirstAncestor("Nowhere") == nil)
+assert(wedge:FindFirstAncestorOfClass("Model") == model)
+assert(wedge:FindFirstAncestorOfClass("DataModel") == game)
+assert(wedge:FindFirstAncestorWhichIsA("Model") == model)
+assert(folder:FindFirstAncestorWhichIsA("WorldRoot") == workspace)
+assert(game:FindFirstAncestorOfClass("Folder") == nil)
+
+assert(model:IsAncestorOf(wedge))
+assert(game:IsAncestorOf(wedge))
+assert(not wedge:IsAncestorOf(model))
+assert(not model:IsAncestorOf(model))
+assert(wedge:IsDescendantOf(workspace))
+assert(not workspace:IsDescendantOf(wedge))
+assert(not wedge:IsDescendantOf(Instance.new("Folder")))
@@ -0,0 +1,44 @@
+local model = Instance.new("Model")
+
+local a = Instance.new("Part")
+a.Name = "A"
+a.Parent = model
+
+local b = Instance.new("Part")
+b.Name = "B"
+b.Parent = model
+
+local weld = Instance.new("Weld")
+weld.Part0 = a
+weld.Part1 = b
+weld.Parent = a
+
+local outside = Instance.new("Part")
+local pointer = Instance.new("ObjectValue")
+pointer.Value = outside
+pointer.Parent = model
+
+model.PrimaryPart = a
+
+local copy = model:Clone()
+local copyA = copy.A
+local copyB = copy.B
+
+assert(copyA ~= a)
+assert(copy.PrimaryPart == copyA)
+assert(copyA.Weld.Part0 == copyA)
+assert(copyA.Weld.Part1 == copyB)
+assert(copy.ObjectValue.Value == outside)
+
+-- The originals should be untouched.
+assert(model.PrimaryPart == a)
+assert(weld.Part1 == b)
+
+-- Instances that aren't archivable are skipped.
+b.Archivable = false
+local withoutB = model:Clone()
+assert(withoutB:FindFirstChild("B") == nil)
+assert(withoutB.A.Weld.Part1 == b)
+
+model.Archivable = false
+assert(model:Clone() == nil)
@@ -0,0 +1,21 @@
+remodel.writeFile("temp/place-artifact.tmp", remodel.readFile("test-models/place-with-models.rbxlx"))
+remodel.writeFile("temp/not-roblox.txt", "Hello, world!")
+
+assert(remodel.detectFileType("test-models/place-with-models.rbxlx") == "xml")
+assert(remodel.detectFileType("test-models/binary.rbxm") == "binary")
+assert(remodel.detectFileType("test-models/binary-without-extension") == "binary")
+assert(remodel.detectFileType("temp/not-roblox.txt") == nil)
+
+-- Files without a known extension are decoded based on their contents.
+local game = remodel.readPlaceFile("temp/place-artifact.tmp")
+assert(game.ClassName == "DataModel")
+
+local models = remodel.readModelFile("test-models/binary-without-extension")
+assert(#models > 0)
+
+-- Files whose contents disagree with their extension are rejected.
+local ok, err = pcall(remodel.readModelFile, "test-models/binary-mislabeled.rbxmx")
+assert(not ok)
+assert(tostring(err):find("contains a binary file"), tostring(err))
+
+assert(not pcall(remodel.readModelFile, "temp/not-roblox.txt"))
@@ -0,0 +1,97 @@
+local old = Instance.new("Folder")
+old.Name = "Root"
+
+local script = Instance.new("Script")
+script.Name = "Main"
+script.Source = "local a = 1\nlocal b = 2\nprint(a + b)\n"
+script.Parent = old
+
+local part = Instance.new("Part")
+part.Name = "Part"
+part.Anchored = false
+part.Parent = old
+
+local from = Instance.new("Folder")
+from.Name = "From"
+from.Parent = old
+
+local to = Instance.new("Folder")
+to.Name = "To"
+to.Parent = old
+
+local mover = Instance.new("ModuleScript")
+mover.Name = "Mover"
+mover.Parent = from
+
+local doomed = Instance.new("Folder")
+doomed.Name = "Doomed"
+doomed.Parent = old
+Instance.new("Folder").Parent = doomed
+
+local value = Instance.new("ObjectValue")
+value.Name = "Pointer"
+value.Value = part
+value.Parent = old
+
+local new = old:Clone()
+
+-- Nothing has changed yet, including the reference that now points to the
+-- copy of the part.
+local same = remodel.diff(old, new)
+assert(#same.added == 0 and #same.removed == 0 and #same.moved == 0 and #same.changed == 0)
+assert(remodel.diff(old, new, { format = "text" }) == "No differences\n")
+
+new.Main.Source = "local a = 1\nlocal b = 3\nprint(a + b)\n"
+new.Part.Anchored = true
+new.From.Mover.Parent = new.To
+new.Doomed:Destroy()
+new.Pointer.Value = nil
+
+local added = Instance.new("StringValue")
+added.Name = "Added"
+added.Parent = new
+
+local diff = remodel.diff(old, new)
+
+assert(#diff.added == 1)
+assert(diff.added[1].path == "Root.Added")
+assert(diff.added[1].className == "StringValue")
+assert(diff.added[1].instance == added)
+
+assert(#diff.removed == 1)
+assert(diff.removed[1].path == "Root.Doomed")
+assert(diff.removed[1].instance == doomed)
+
+assert(#diff.moved == 1)
+assert(diff.moved[1].from == "Root.From.Mover")
+assert(diff.moved[1].to == "Root.To.Mover")
+assert(diff.moved[1].instance == new.To.Mover)
+
+assert(#diff.changed == 3)
+
+local changed = {}
+for _, change in ipairs(diff.changed) do
+	changed[change.path] = change
+end
+
+local anchored = changed["Root.Part"].properties.Anchored
+assert(anchored.old.type == "Bool" and anchored.old.value == false)
+assert(anchored.new.type == "Bool" and anchored.new.value == true)
+
+local source = changed["Root.Main"].properties.Source
+assert(source.new.value == new.Main.Source)
+assert(source.diff:find("-local b = 2", 1, true))
+assert(source.diff:find("+local b = 3", 1, true))
+
+local pointer = changed["Root.Pointer"].properties.Value
+assert(pointer.old.value == part)
+assert(pointer.new.value == nil)
+
+local decoded = json.fromString(remodel.diff(old, new, { format = "json" }))
+assert(decoded.added[1].path == "Root.Added")
+assert(decoded.moved[1].to == "Root.To.Mover")
+assert(decoded.changed[1].properties.Source.diff == source.diff)
+
+print(remodel.diff(old, new, { format = "text" }))
+
+assert(not pcall(remodel.diff, old, new, { format = "xml" }))
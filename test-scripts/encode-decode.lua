@@ -0,0 +1,38 @@
+local folder = Instance.new("Folder")
+folder.Name = "Root"
+
+local value = Instance.new("StringValue")
+value.Name = "Greeting"
+value.Value = "Hello, world!"
+value.Parent = folder
+
+for _, format in ipairs({ "rbxm", "rbxmx" }) do
+	local encoded = remodel.encodeModel(folder, format)
+	assert(type(encoded) == "string")
+
+	local decoded = remodel.decodeModel(encoded)
+	assert(#decoded == 1, format)
+	assert(decoded[1].Name == "Root", format)
+	assert(decoded[1].Greeting.Value == "Hello, world!", format)
+end
+
+assert(remodel.encodeModel(folder, "rbxm"):sub(1, 8) == "<roblox!")
+assert(not pcall(remodel.encodeModel, folder, "txt"))
+
+local game = Instance.new("DataModel")
+local workspace = game:GetService("Workspace")
+folder:Clone().Parent = workspace
+
+for _, format in ipairs({ "rbxl", "rbxlx" }) do
+	local decoded = remodel.decodePlace(remodel.encodePlace(game, format))
+	assert(decoded.ClassName == "DataModel", format)
+	assert(decoded.Workspace.Root.Greeting.Value == "Hello, world!", format)
+end
+
+assert(not pcall(remodel.encodePlace, folder, "rbxl"))
+assert(not pcall(remodel.encodeModel, game, "rbxm"))
+assert(not pcall(remodel.decodeModel, "not a model"))
+
+-- Encoding should match what gets written to disk.
+remodel.writeModelFile("temp/encode-decode.rbxmx", folder)
+assert(remodel.readFile("temp/encode-decode.rbxmx") == remodel.encodeModel(folder, "rbxmx"))
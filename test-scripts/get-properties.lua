@@ -0,0 +1,38 @@
+local part = Instance.new("Part")
+
+local defaults = remodel.getDefaultProperties("Part")
+assert(defaults.Anchored.type == "Bool")
+assert(defaults.Anchored.value == false)
+assert(defaults.Size.type == "Vector3")
+assert(defaults.Size.value == part.Size)
+assert(defaults.Shape.type == "Enum")
+assert(not pcall(remodel.getDefaultProperties, "NotAClass"))
+
+-- New instances don't store any properties until they're set.
+assert(next(remodel.getProperties(part)) == nil)
+
+local model = Instance.new("Model")
+part.Parent = model
+model.PrimaryPart = part
+
+part.Anchored = true
+part.Transparency = 0.5
+part.CastShadow = true
+
+local properties = remodel.getProperties(part)
+assert(properties.Anchored.type == "Bool")
+assert(properties.Anchored.value == true)
+assert(properties.Transparency.type == "Float32")
+assert(properties.Transparency.value == 0.5)
+assert(properties.CastShadow.value == true)
+
+assert(remodel.getProperties(model).PrimaryPart.type == "Ref")
+assert(remodel.getProperties(model).PrimaryPart.value == part)
+
+local changed = remodel.getProperties(part, { excludeDefaults = true })
+assert(changed.Anchored ~= nil)
+assert(changed.Transparency ~= nil)
+assert(changed.CastShadow == nil)
+
+local value = remodel.readModelFile("test-models/folder-and-value.rbxmx")[1].String
+assert(remodel.getProperties(value).Value.type == "String")
@@ -0,0 +1,176 @@
+local base = Instance.new("Folder")
+base.Name = "Root"
+
+local part = Instance.new("Part")
+part.Name = "Part"
+part.Anchored = false
+part.Parent = base
+
+local script = Instance.new("Script")
+script.Name = "Main"
+script.Source = "print('base')"
+script.Parent = base
+
+local from = Instance.new("Folder")
+from.Name = "From"
+from.Parent = base
+
+local mover = Instance.new("ModuleScript")
+mover.Name = "Mover"
+mover.Parent = from
+
+local to = Instance.new("Folder")
+to.Name = "To"
+to.Parent = base
+
+local unused = Instance.new("Folder")
+unused.Name = "Unused"
+unused.Parent = base
+
+local edited = Instance.new("StringValue")
+edited.Name = "Edited"
+edited.Value = "base"
+edited.Parent = base
+
+local value = Instance.new("ObjectValue")
+value.Name = "Pointer"
+value.Parent = base
+
+local ours = base:Clone()
+local theirs = base:Clone()
+
+-- Changes that don't overlap are combined.
+ours.Part.Anchored = true
+theirs.From.Mover.Parent = theirs.To
+theirs.Unused:Destroy()
+theirs.Pointer.Value = theirs.Part
+
+local ourAddition = Instance.new("Folder")
+ourAddition.Name = "OurAddition"
+ourAddition.Parent = ours
+
+local theirAddition = Instance.new("Folder")
+theirAddition.Name = "TheirAddition"
+theirAddition.Parent = theirs
+
+-- Changes that overlap are conflicts.
+ours.Main.Source = "print('ours')"
+theirs.Main.Source = "print('theirs')"
+ours.Edited:Destroy()
+theirs.Edited.Value = "theirs"
+
+local merged, conflicts = remodel.merge(base, ours, theirs)
+
+assert(merged.Name == "Root")
+assert(merged.Parent == nil)
+assert(merged ~= ours and merged ~= theirs)
+
+assert(merged.Part.Anchored == true)
+assert(merged.To.Mover.ClassName == "ModuleScript")
+assert(#merged.From:GetChildren() == 0)
+assert(merged:FindFirstChild("Unused") == nil)
+assert(merged.Pointer.Value == merged.Part)
+assert(merged.OurAddition.ClassName == "Folder")
+assert(merged.TheirAddition.ClassName == "Folder")
+assert(merged.Main.Source == "print('ours')")
+assert(merged:FindFirstChild("Edited") == nil)
+
+-- The sides that were merged aren't changed.
+assert(ours.Part.Anchored == true)
+assert(theirs.Part.Anchored == false)
+
+assert(#conflicts == 2)
+
+local byKind = {}
+for _, conflict in ipairs(conflicts) do
+	byKind[conflict.kind] = conflict
+end
+
+local property = byKind.property
+assert(property.path == "Root.Main")
+assert(property.instance == merged.Main)
+assert(property.property == "Source")
+assert(property.baseValue.value == "print('base')")
+assert(property.oursValue.value == "print('ours')")
+assert(property.theirsValue.value == "print('theirs')")
+assert(property.ours == ours.Main)
+assert(property.theirs == theirs.Main)
+
+local deleted = byKind.deleted
+assert(deleted.path == "Root.Edited")
+assert(deleted.deletedIn == "ours")
+assert(deleted.instance == nil)
+assert(deleted.theirs == theirs.Edited)
+assert(deleted.theirsParent == merged)
+
+-- When they delete something we changed, it's kept.
+local other = base:Clone()
+other.Edited:Destroy()
+local keptMerged, keptConflicts = remodel.merge(base, theirs, other)
+assert(keptMerged.Edited.Value == "theirs")
+assert(#keptConflicts == 1)
+assert(keptConflicts[1].kind == "deleted")
+assert(keptConflicts[1].deletedIn == "theirs")
+assert(keptConflicts[1].instance == keptMerged.Edited)
+
+-- Instances added by both sides in the same place are the same instance.
+local left = base:Clone()
+local right = base:Clone()
+for _, side in ipairs({ left, right }) do
+	local folder = Instance.new("Folder")
+	folder.Name = "Both"
+	folder.Parent = side
+end
+left.Main.Parent = left.From
+right.Main.Parent = right.To
+
+local both, bothConflicts = remodel.merge(base, left, right)
+local bothCount = 0
+for _, child in ipairs(both:GetChildren()) do
+	if child.Name == "Both" then
+		bothCount = bothCount + 1
+	end
+end
+assert(bothCount == 1)
+assert(both.From.Main.ClassName == "Script")
+assert(#bothConflicts == 1)
+assert(bothConflicts[1].kind == "moved")
+assert(bothConflicts[1].theirsParent == both.To)
+
+
+-- Moving two instances into each other would make a cycle. Only the instance
+-- that their side moved goes back to where our side has it.
+local swappedOurs = base:Clone()
+local swappedTheirs = base:Clone()
+swappedOurs.From.Parent = swappedOurs.To
+swappedTheirs.To.Parent = swappedTheirs.From
+
+local swapped, swappedConflicts = remodel.merge(base, swappedOurs, swappedTheirs)
+assert(swapped.To.From.Mover.ClassName == "ModuleScript")
+assert(#swappedConflicts == 1)
+assert(swappedConflicts[1].kind == "moved")
+assert(swappedConflicts[1].instance == swapped.To)
+
+-- When each side deletes the parent the other side has an instance in, the
+-- instance goes where the parent it was moved to used to be.
+local orphanOurs = base:Clone()
+local orphanTheirs = base:Clone()
+orphanOurs.To:Destroy()
+orphanTheirs.From.Mover.Parent = orphanTheirs.To
+orphanTheirs.Pointer.Value = orphanTheirs.To.Mover
+orphanTheirs.From:Destroy()
+
+local orphaned, orphanConflicts = remodel.merge(base, orphanOurs, orphanTheirs)
+assert(orphaned:FindFirstChild("From") == nil)
+assert(orphaned:FindFirstChild("To") == nil)
+assert(orphaned.Mover.ClassName == "ModuleScript")
+assert(orphaned.Pointer.Value == orphaned.Mover)
+
+local orphanMoved = 0
+for _, conflict in ipairs(orphanConflicts) do
+	if conflict.kind == "moved" then
+		orphanMoved = orphanMoved + 1
+		assert(conflict.instance == orphaned.Mover)
+	end
+end
+assert(orphanMoved == 1)
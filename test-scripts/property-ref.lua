@@ -0,0 +1,38 @@
+local model = Instance.new("Model")
+local part = Instance.new("Part")
+part.Parent = model
+
+assert(model.PrimaryPart == nil)
+model.PrimaryPart = part
+assert(model.PrimaryPart == part)
+model.PrimaryPart = nil
+assert(model.PrimaryPart == nil)
+
+local value = Instance.new("ObjectValue")
+value.Value = model
+assert(value.Value == model)
+assert(value.Value.PrimaryPart == nil)
+
+local weld = Instance.new("Weld")
+weld.Part0 = part
+assert(weld.Part0 == part)
+assert(weld.Part1 == nil)
+
+assert(not pcall(function()
+	weld.Part1 = "Part"
+end))
+
+local destroyed = Instance.new("Part")
+destroyed:Destroy()
+assert(not pcall(function()
+	weld.Part1 = destroyed
+end))
+
+-- Referents to instances that are destroyed later read as nil.
+local other = Instance.new("Part")
+weld.Part1 = other
+other:Destroy()
+assert(weld.Part1 == nil)
+
+remodel.setRawProperty(value, "Value", "Ref", part)
+assert(remodel.getRawProperty(value, "Value") == part)
@@ -0,0 +1,47 @@
+local part = Instance.new("Part")
+
+-- Properties that have not been set fall back to their defaults.
+assert(part.Anchored == false)
+assert(part.Transparency == 0)
+assert(part.Size == Vector3.new(4, 1.2, 2))
+
+part.Anchored = true
+part.Transparency = 0.5
+part.Size = Vector3.new(1, 2, 3)
+
+assert(part.Anchored == true)
+assert(part.Transparency == 0.5)
+assert(part.Size == Vector3.new(1, 2, 3))
+assert(remodel.getRawProperty(part, "Anchored") == true)
+
+-- Aliases resolve to their canonical property.
+part.archivable = false
+assert(part.Archivable == false)
+
+-- Inherited properties work on any class.
+local value = Instance.new("StringValue")
+value.Value = "Hello"
+assert(value.Value == "Hello")
+
+-- Properties take priority over children with the same name.
+local child = Instance.new("Folder")
+child.Name = "Value"
+child.Parent = value
+assert(value.Value == "Hello")
+
+local ok, err = pcall(function()
+	part.Anchored = "yes"
+end)
+assert(not ok)
+assert(tostring(err):find("Anchored"), tostring(err))
+
+ok, err = pcall(function()
+	part.Mass = 5
+end)
+assert(not ok)
+assert(tostring(err):find("read%-only"), tostring(err))
+
+ok = pcall(function()
+	part.NotARealProperty = 5
+end)
+assert(not ok)
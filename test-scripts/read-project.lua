@@ -0,0 +1,64 @@
+local game = remodel.readProject("test-projects/place")
+assert(game.ClassName == "DataModel")
+assert(game.Name == "Test Place")
+
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+local shared = ReplicatedStorage.Shared
+assert(shared.ClassName == "ModuleScript")
+assert(shared.Source == 'return "shared"\n')
+assert(shared.Util.ClassName == "ModuleScript")
+assert(shared.Util.Math.Source == "return 1\n")
+assert(shared.Client.ClassName == "LocalScript")
+
+assert(shared.Greeting.ClassName == "StringValue")
+assert(shared.Greeting.Value == "Hello, world!")
+assert(shared.Greeting.Archivable == false)
+
+local config = load(shared.Config.Source)()
+assert(config.name == 'Test "Place"')
+assert(config.players == 8)
+assert(config.enabled == true)
+assert(config.tags[2] == "b")
+assert(config.control == "\1" .. "5")
+assert(config.nextLine == "\u{85}")
+
+local strings = json.fromString(remodel.getRawProperty(shared.Strings, "Contents"))
+assert(shared.Strings.ClassName == "LocalizationTable")
+assert(#strings == 2)
+assert(strings[1].key == "Greeting")
+assert(strings[1].values.es == "Hola")
+assert(strings[2].source == "Goodbye, friend")
+assert(strings[2].values.fr == nil)
+
+assert(ReplicatedStorage.Nested.ClassName == "Folder")
+assert(ReplicatedStorage.Nested.Module.Source == 'return "nested"\n')
+assert(ReplicatedStorage:FindFirstChild("Missing") == nil)
+
+local server = game:GetService("ServerScriptService")
+assert(server.ClassName == "ServerScriptService")
+assert(server.Main.ClassName == "Script")
+assert(server.Settings.MaxPlayers.Value == 8)
+assert(server.Settings.BoolValue.Value == true)
+assert(server.Modules.ClassName == "Configuration")
+assert(server.Modules.Feature.ClassName == "ModuleScript")
+
+local workspace = game:GetService("Workspace")
+assert(workspace.Gravity == 100)
+
+local baseplate = workspace.Baseplate
+assert(baseplate.Anchored == true)
+assert(baseplate.Size == Vector3.new(512, 20, 512))
+assert(baseplate.Material == Enum.Material.Grass)
+assert(baseplate:HasTag("Ground"))
+assert(baseplate:GetAttribute("Version") == "1.0.0")
+assert(baseplate:GetAttribute("Level") == 3)
+
+assert(workspace.Tree.ClassName == "Model")
+assert(workspace.Tree.Trunk.ClassName == "Part")
+
+-- Projects can also be read from the project file directly.
+local nested = remodel.readProject("test-projects/place/nested/default.project.json")
+assert(nested.Name == "Nested")
+assert(nested.Module ~= nil)
+
+assert(not pcall(remodel.readProject, "test-projects/does-not-exist"))
@@ -0,0 +1,7 @@
+loadCount = (loadCount or 0) + 1
+
+return {
+	hello = function(name)
+		return "Hello, " .. name
+	end,
+}
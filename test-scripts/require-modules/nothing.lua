@@ -0,0 +1 @@
+local _ = 1
@@ -0,0 +1,6 @@
+-- Modules are found next to the script that requires them.
+local helper = require("helper")
+
+return {
+	helperName = helper.name,
+}
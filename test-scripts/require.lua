@@ -0,0 +1,26 @@
+local greeting = require("require-modules.greeting")
+assert(greeting.hello("Remodel") == "Hello, Remodel")
+
+-- Modules are only loaded once, no matter how they're named.
+assert(require("require-modules/greeting") == greeting)
+assert(require("./require-modules/greeting.lua") == greeting)
+assert(loadCount == 1)
+
+-- Directories can be required through their init.lua.
+assert(require("require-modules.package").helperName == "helper")
+
+assert(require("require-modules.nothing") == true)
+
+local ok, err = pcall(require, "require-modules.cycle-a")
+err = tostring(err)
+assert(not ok)
+assert(err:find("Cyclic require"), err)
+assert(err:find("cycle%-a%.lua %-> .*cycle%-b%.lua %-> .*cycle%-a%.lua"), err)
+
+-- Modules that aren't files next to the script are found the usual way.
+assert(require("string") == string)
+
+ok, err = pcall(require, "require-modules.missing")
+err = tostring(err)
+assert(not ok)
+assert(err:find("require%-modules/missing%.lua"), err)
@@ -0,0 +1,46 @@
+local game = Instance.new("DataModel")
+local workspace = game:GetService("Workspace")
+
+local part = Instance.new("Part")
+part.Parent = workspace
+
+assert(not part:HasTag("Export"))
+assert(#part:GetTags() == 0)
+
+part:AddTag("Export")
+part:AddTag("Export")
+part:AddTag("Large")
+assert(part:HasTag("Export"))
+assert(#part:GetTags() == 2)
+
+part:RemoveTag("Large")
+assert(not part:HasTag("Large"))
+assert(#part:GetTags() == 1)
+
+local folder = Instance.new("Folder")
+folder:AddTag("Export")
+folder.Parent = workspace
+
+local untagged = Instance.new("Folder")
+untagged.Parent = workspace
+
+local elsewhere = Instance.new("Folder")
+elsewhere:AddTag("Export")
+
+local CollectionService = game:GetService("CollectionService")
+local tagged = CollectionService:GetTagged("Export")
+assert(#tagged == 2)
+assert(tagged[1] == part)
+assert(tagged[2] == folder)
+assert(#CollectionService:GetTagged("Missing") == 0)
+
+assert(not pcall(part.GetTagged, part, "Export"))
+
+-- Tags should survive being written to and read from both formats.
+remodel.writeModelFile("temp/tags.rbxm", part)
+remodel.writeModelFile("temp/tags.rbxmx", part)
+
+for _, path in ipairs({ "temp/tags.rbxm", "temp/tags.rbxmx" }) do
+	local copy = remodel.readModelFile(path)[1]
+	assert(copy:HasTag("Export"), path)
+end
@@ -0,0 +1,32 @@
+local red = BrickColor.new("Bright red")
+assert(red.Name == "Bright red")
+assert(red.Number == 21)
+assert(red.Color == Color3.fromRGB(196, 40, 28))
+assert(tostring(red) == "Bright red")
+
+assert(BrickColor.new(21) == red)
+assert(BrickColor.new(Color3.fromRGB(200, 40, 30)) == red)
+assert(BrickColor.new(196 / 255, 40 / 255, 28 / 255) == red)
+
+-- Unknown names and numbers fall back to the default, like in Roblox.
+assert(BrickColor.new("Not a color").Name == "Medium stone grey")
+assert(BrickColor.new(9999).Name == "Medium stone grey")
+
+assert(BrickColor.palette(0).Name == "Earth green")
+assert(not pcall(BrickColor.palette, 1000))
+
+math.randomseed(0)
+local random = BrickColor.random()
+assert(BrickColor.new(random.Number) == random)
+
+local part = Instance.new("Part")
+part.BrickColor = red
+assert(part.BrickColor == red)
+assert(part.Color == red.Color)
+
+part.Color = Color3.fromRGB(13, 105, 172)
+assert(part.BrickColor.Name == "Bright blue")
+
+local spawn = Instance.new("SpawnLocation")
+spawn.TeamColor = BrickColor.new("Bright yellow")
+assert(spawn.TeamColor.Number == 24)
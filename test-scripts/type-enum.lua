@@ -0,0 +1,55 @@
+local plastic = Enum.Material.Plastic
+
+assert(plastic.Name == "Plastic")
+assert(plastic.Value == 256)
+assert(plastic.EnumType == Enum.Material)
+assert(tostring(plastic) == "Enum.Material.Plastic")
+assert(tostring(Enum.Material) == "Material")
+assert(plastic == Enum.Material.Plastic)
+assert(plastic ~= Enum.Material.Wood)
+
+local items = Enum.SurfaceType:GetEnumItems()
+assert(#items > 0)
+assert(items[1] == Enum.SurfaceType.Smooth)
+
+local found = false
+for _, enum in ipairs(Enum:GetEnums()) do
+	if enum == Enum.Material then
+		found = true
+	end
+end
+assert(found)
+
+assert(not pcall(function()
+	return Enum.NotARealEnum
+end))
+assert(not pcall(function()
+	return Enum.Material.NotARealMaterial
+end))
+
+local part = Instance.new("Part")
+
+-- Unset enum properties read as their default.
+assert(part.Material == Enum.Material.Plastic)
+
+part.Material = Enum.Material.Wood
+assert(part.Material == Enum.Material.Wood)
+
+-- Names and numbers are accepted as a convenience.
+part.Material = "Neon"
+assert(part.Material == Enum.Material.Neon)
+
+part.TopSurface = 0
+assert(part.TopSurface == Enum.SurfaceType.Smooth)
+
+-- Items from the wrong enum are rejected.
+assert(not pcall(function()
+	part.Material = Enum.SurfaceType.Smooth
+end))
+assert(not pcall(function()
+	part.Material = "NotARealMaterial"
+end))
+
+assert(remodel.getRawProperty(part, "TopSurface") == 0)
+remodel.setRawProperty(part, "Material", "Enum", Enum.Material.Grass)
+assert(part.Material == Enum.Material.Grass)
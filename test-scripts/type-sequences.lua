@@ -0,0 +1,66 @@
+local range = NumberRange.new(1, 2)
+assert(range.Min == 1)
+assert(range.Max == 2)
+assert(NumberRange.new(3) == NumberRange.new(3, 3))
+assert(not pcall(NumberRange.new, 2, 1))
+
+local red = Color3.new(1, 0, 0)
+local blue = Color3.new(0, 0, 1)
+
+local solid = ColorSequence.new(red)
+assert(#solid.Keypoints == 2)
+assert(solid.Keypoints[1].Time == 0)
+assert(solid.Keypoints[1].Value == red)
+assert(solid.Keypoints[2].Time == 1)
+assert(solid.Keypoints[2].Value == red)
+
+local gradient = ColorSequence.new(red, blue)
+assert(gradient.Keypoints[2].Value == blue)
+
+local fromKeypoints = ColorSequence.new({
+	ColorSequenceKeypoint.new(0, red),
+	ColorSequenceKeypoint.new(0.5, blue),
+	ColorSequenceKeypoint.new(1, red),
+})
+assert(#fromKeypoints.Keypoints == 3)
+assert(fromKeypoints.Keypoints[2] == ColorSequenceKeypoint.new(0.5, blue))
+assert(fromKeypoints == ColorSequence.new({
+	ColorSequenceKeypoint.new(0, red),
+	ColorSequenceKeypoint.new(0.5, blue),
+	ColorSequenceKeypoint.new(1, red),
+}))
+
+-- Keypoints must cover 0 to 1 in order.
+assert(not pcall(ColorSequence.new, { ColorSequenceKeypoint.new(0, red) }))
+assert(not pcall(ColorSequence.new, {
+	ColorSequenceKeypoint.new(0.5, red),
+	ColorSequenceKeypoint.new(1, red),
+}))
+assert(not pcall(ColorSequence.new, {
+	ColorSequenceKeypoint.new(0, red),
+	ColorSequenceKeypoint.new(0.8, red),
+	ColorSequenceKeypoint.new(0.5, red),
+	ColorSequenceKeypoint.new(1, red),
+}))
+
+local numbers = NumberSequence.new(1, 5)
+assert(numbers.Keypoints[1].Value == 1)
+assert(numbers.Keypoints[2].Value == 5)
+assert(numbers.Keypoints[2].Envelope == 0)
+assert(NumberSequence.new(2) == NumberSequence.new(2, 2))
+
+local withEnvelope = NumberSequence.new({
+	NumberSequenceKeypoint.new(0, 1, 0.5),
+	NumberSequenceKeypoint.new(1, 2),
+})
+assert(withEnvelope.Keypoints[1].Envelope == 0.5)
+assert(withEnvelope.Keypoints[2].Envelope == 0)
+
+local emitter = Instance.new("ParticleEmitter")
+emitter.Color = gradient
+emitter.Size = withEnvelope
+emitter.Lifetime = NumberRange.new(2, 4)
+
+assert(emitter.Color == gradient)
+assert(emitter.Size == withEnvelope)
+assert(emitter.Lifetime == NumberRange.new(2, 4))
@@ -0,0 +1,38 @@
+local function assertUDim(udim, scale, offset)
+    assert(udim.Scale == scale, ("scale: %f ~= %f"):format(udim.Scale, scale))
+    assert(udim.Offset == offset, ("offset: %d ~= %d"):format(udim.Offset, offset))
+end
+
+assertUDim(UDim.new(), 0, 0)
+assertUDim(UDim.new(0.5, 10), 0.5, 10)
+assertUDim(UDim.new(0.5, 10) + UDim.new(0.25, 5), 0.75, 15)
+assertUDim(UDim.new(0.5, 10) - UDim.new(0.25, 5), 0.25, 5)
+assertUDim(-UDim.new(0.5, 10), -0.5, -10)
+assert(UDim.new(1, 2) == UDim.new(1, 2))
+assert(tostring(UDim.new(1, 2)) == "1, 2")
+
+local size = UDim2.new(0.5, 10, 1, 20)
+assertUDim(size.X, 0.5, 10)
+assertUDim(size.Y, 1, 20)
+assertUDim(size.Width, 0.5, 10)
+assertUDim(size.Height, 1, 20)
+assert(tostring(size) == "{0.5, 10}, {1, 20}")
+
+assert(UDim2.new() == UDim2.new(0, 0, 0, 0))
+assert(UDim2.new(UDim.new(0.5, 10), UDim.new(1, 20)) == size)
+assert(UDim2.fromScale(0.5, 1) == UDim2.new(0.5, 0, 1, 0))
+assert(UDim2.fromOffset(10, 20) == UDim2.new(0, 10, 0, 20))
+
+assert(size + UDim2.new(0.5, 10, 0, 0) == UDim2.new(1, 20, 1, 20))
+assert(size - UDim2.new(0.5, 10, 0, 0) == UDim2.new(0, 0, 1, 20))
+assert(-size == UDim2.new(-0.5, -10, -1, -20))
+
+local lerped = UDim2.new(0, 0, 0, 0):Lerp(UDim2.new(1, 100, 0.5, 50), 0.5)
+assert(lerped == UDim2.new(0.5, 50, 0.25, 25), tostring(lerped))
+
+local frame = Instance.new("Frame")
+frame.Size = size
+assert(frame.Size == size)
+
+remodel.setRawProperty(frame, "Position", "UDim2", UDim2.new(0, 1, 0, 2))
+assert(remodel.getRawProperty(frame, "Position") == UDim2.new(0, 1, 0, 2))
@@ -0,0 +1,63 @@
+local EPSILON = 1e-5
+
+local function assertVector(vec, x, y)
+	assert(math.abs(vec.X - x) < EPSILON, ("x: %f ~= %f (%s)"):format(vec.X, x, tostring(vec)))
+	assert(math.abs(vec.Y - y) < EPSILON, ("y: %f ~= %f (%s)"):format(vec.Y, y, tostring(vec)))
+end
+
+assertVector(Vector2.new(), 0, 0)
+assertVector(Vector2.new(1), 1, 0)
+assertVector(Vector2.new(1, 2), 1, 2)
+assert(tostring(Vector2.new(1, 2)) == "1, 2")
+assert(Vector2.new(1, 2) == Vector2.new(1, 2))
+assert(Vector2.new(1, 2) ~= Vector2.new(2, 1))
+
+local a = Vector2.new(1, 2)
+local b = Vector2.new(4, 6)
+
+assertVector(a + b, 5, 8)
+assertVector(b - a, 3, 4)
+assertVector(a * 2, 2, 4)
+assertVector(2 * a, 2, 4)
+assertVector(a * b, 4, 12)
+assertVector(b / 2, 2, 3)
+assertVector(b / a, 4, 3)
+assertVector(-a, -1, -2)
+
+assert((b - a).Magnitude == 5)
+assertVector((b - a).Unit, 0.6, 0.8)
+-- Like in Roblox, the zero vector has no direction.
+local zeroUnit = Vector2.zero.Unit
+assert(zeroUnit.X ~= zeroUnit.X and zeroUnit.Y ~= zeroUnit.Y)
+assert(a:Dot(b) == 16)
+assert(Vector2.xAxis:Cross(Vector2.yAxis) == 1)
+assertVector(a:Lerp(b, 0.5), 2.5, 4)
+assert(a:FuzzyEq(a + Vector2.new(1e-7, 0)))
+assertVector(Vector2.new(1, 5):Min(Vector2.new(2, 2)), 1, 2)
+assertVector(Vector2.new(1, 5):Max(Vector2.new(2, 2)), 2, 5)
+assertVector(Vector2.new(-1.5, 1.5):Abs(), 1.5, 1.5)
+assertVector(Vector2.new(-1.5, 1.5):Floor(), -2, 1)
+assertVector(Vector2.new(-1.5, 1.5):Ceil(), -1, 2)
+assertVector(Vector2.new(-3, 0):Sign(), -1, 0)
+
+assertVector(Vector2.zero, 0, 0)
+assertVector(Vector2.one, 1, 1)
+assertVector(Vector2.xAxis, 1, 0)
+assertVector(Vector2.yAxis, 0, 1)
+
+local c = Vector2int16.new(2, 4)
+assertVector(c, 2, 4)
+assert(tostring(c) == "2, 4")
+assertVector(c + Vector2int16.new(1, 1), 3, 5)
+assertVector(c - Vector2int16.new(1, 1), 1, 3)
+assertVector(c * 2, 4, 8)
+assertVector(c / 2, 1, 2)
+assertVector(-c, -2, -4)
+
+local label = Instance.new("ImageLabel")
+label.ImageRectOffset = Vector2.new(10, 20)
+assert(label.ImageRectOffset == Vector2.new(10, 20))
+assert(label.ImageRectSize == Vector2.new(0, 0))
+
+remodel.setRawProperty(label, "Foo", "Vector2int16", Vector2int16.new(3, 4))
+assert(remodel.getRawProperty(label, "Foo") == Vector2int16.new(3, 4))
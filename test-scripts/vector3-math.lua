@@ -0,0 +1,54 @@
+local EPSILON = 1e-5
+
+local function assertVector(vec, x, y, z)
+	assert(math.abs(vec.X - x) < EPSILON, ("x: %f ~= %f (%s)"):format(vec.X, x, tostring(vec)))
+	assert(math.abs(vec.Y - y) < EPSILON, ("y: %f ~= %f (%s)"):format(vec.Y, y, tostring(vec)))
+	assert(math.abs(vec.Z - z) < EPSILON, ("z: %f ~= %f (%s)"):format(vec.Z, z, tostring(vec)))
+end
+
+local a = Vector3.new(1, 2, 3)
+local b = Vector3.new(4, 5, 6)
+
+assertVector(a * 2, 2, 4, 6)
+assertVector(2 * a, 2, 4, 6)
+assertVector(a * b, 4, 10, 18)
+assertVector(b / 2, 2, 2.5, 3)
+assertVector(b / a, 4, 2.5, 2)
+assertVector(-a, -1, -2, -3)
+
+assert(Vector3.new(3, 4, 0).Magnitude == 5)
+assertVector(Vector3.new(3, 4, 0).Unit, 0.6, 0.8, 0)
+-- Like in Roblox, the zero vector has no direction.
+local zeroUnit = Vector3.zero.Unit
+assert(zeroUnit.X ~= zeroUnit.X and zeroUnit.Y ~= zeroUnit.Y and zeroUnit.Z ~= zeroUnit.Z)
+assert(a:Dot(b) == 32)
+assertVector(Vector3.xAxis:Cross(Vector3.yAxis), 0, 0, 1)
+assertVector(a:Lerp(b, 0.5), 2.5, 3.5, 4.5)
+
+assert(a:FuzzyEq(a + Vector3.new(1e-7, 0, 0)))
+assert(not a:FuzzyEq(b))
+assert(a:FuzzyEq(a + Vector3.new(0.05, 0, 0), 0.1))
+
+assertVector(Vector3.new(1, 5, 3):Min(Vector3.new(2, 2, 2), Vector3.new(3, 3, 1)), 1, 2, 1)
+assertVector(Vector3.new(1, 5, 3):Max(Vector3.new(2, 2, 2)), 2, 5, 3)
+assertVector(Vector3.new(-1, 2, -3):Abs(), 1, 2, 3)
+assertVector(Vector3.new(1.5, -1.5, 2):Floor(), 1, -2, 2)
+assertVector(Vector3.new(1.5, -1.5, 2):Ceil(), 2, -1, 2)
+assertVector(Vector3.new(-5, 0, 5):Sign(), -1, 0, 1)
+
+assertVector(Vector3.zero, 0, 0, 0)
+assertVector(Vector3.one, 1, 1, 1)
+assertVector(Vector3.xAxis, 1, 0, 0)
+assertVector(Vector3.yAxis, 0, 1, 0)
+assertVector(Vector3.zAxis, 0, 0, 1)
+
+local c = Vector3int16.new(2, 4, 6)
+assertVector(c * 2, 4, 8, 12)
+assertVector(2 * c, 4, 8, 12)
+assertVector(c * Vector3int16.new(1, 2, 3), 2, 8, 18)
+assertVector(c / 2, 1, 2, 3)
+assertVector(c / Vector3int16.new(2, 4, 6), 1, 1, 1)
+assertVector(-c, -2, -4, -6)
+assert(not pcall(function()
+	return c / 0
+end))
@@ -0,0 +1,105 @@
+local root = Instance.new("Folder")
+root.Name = "Root"
+
+local main = Instance.new("Script")
+main.Name = "Main"
+main.Source = "print('main')"
+main.Parent = root
+
+local lib = Instance.new("ModuleScript")
+lib.Name = "Lib"
+lib.Source = "return {}"
+lib.Parent = root
+
+local helper = Instance.new("ModuleScript")
+helper.Name = "Helper"
+helper.Source = "return 1"
+helper.Parent = lib
+
+local client = Instance.new("LocalScript")
+client.Name = "Client"
+client.Disabled = true
+client.Parent = root
+
+local text = Instance.new("StringValue")
+text.Name = "Text"
+text.Value = "Hello, world!"
+text.Parent = root
+
+local config = Instance.new("Configuration")
+config.Name = "Config"
+config:SetAttribute("Speed", 16)
+config:SetAttribute("Spawn", Vector3.new(1, 2, 3))
+config:AddTag("Settings")
+config.Parent = root
+
+local flag = Instance.new("BoolValue")
+flag.Name = "Flag"
+flag.Value = true
+flag.Parent = config
+
+local dupes = Instance.new("Folder")
+dupes.Name = "Dupes"
+dupes.Parent = root
+
+for _ = 1, 2 do
+	local same = Instance.new("Folder")
+	same.Name = "Same"
+	same.Parent = dupes
+end
+
+local function isFile(path)
+	local ok, isFile = pcall(remodel.isFile, path)
+	return ok and isFile
+end
+
+local path = "temp/dir-tree"
+pcall(remodel.removeDir, path)
+
+remodel.writeDirTree(root, path)
+
+assert(remodel.readFile(path .. "/Main.server.lua") == "print('main')")
+assert(remodel.readFile(path .. "/Lib/init.lua") == "return {}")
+assert(remodel.readFile(path .. "/Lib/Helper.lua") == "return 1")
+assert(remodel.readFile(path .. "/Client.client.lua") == "")
+assert(json.fromString(remodel.readFile(path .. "/Client.meta.json")).properties.Disabled == true)
+assert(remodel.readFile(path .. "/Text.txt") == "Hello, world!")
+assert(not isFile(path .. "/Text.meta.json"))
+assert(not isFile(path .. "/init.meta.json"))
+
+local configMeta = json.fromString(remodel.readFile(path .. "/Config/init.meta.json"))
+assert(configMeta.className == "Configuration")
+assert(configMeta.attributes.Speed == 16)
+assert(configMeta.attributes.Spawn.Vector3[3] == 3)
+assert(configMeta.properties.Tags[1] == "Settings")
+
+-- Instances that can't be written as files become models.
+assert(isFile(path .. "/Config/Flag.rbxmx"))
+assert(isFile(path .. "/Dupes.rbxmx"))
+
+remodel.writeFile("temp/dir-tree.project.json", json.toString({
+	name = "Root",
+	tree = { ["$path"] = "dir-tree" },
+}))
+
+local copy = remodel.readProject("temp/dir-tree.project.json")
+assert(copy.ClassName == "Folder")
+assert(copy.Main.ClassName == "Script")
+assert(copy.Main.Source == "print('main')")
+assert(copy.Lib.Helper.Source == "return 1")
+assert(copy.Client.ClassName == "LocalScript")
+assert(copy.Client.Disabled == true)
+assert(copy.Text.Value == "Hello, world!")
+assert(copy.Config.ClassName == "Configuration")
+assert(copy.Config:GetAttribute("Speed") == 16)
+assert(copy.Config:GetAttribute("Spawn") == Vector3.new(1, 2, 3))
+assert(copy.Config:HasTag("Settings"))
+assert(copy.Config.Flag.Value == true)
+assert(#copy.Dupes:GetChildren() == 2)
+
+remodel.writeDirTree(config, "temp/dir-tree-binary", { modelFormat = "rbxm" })
+assert(isFile("temp/dir-tree-binary/Flag.rbxm"))
+
+-- The root has to be a directory, so it can't fall back to a model.
+assert(not pcall(remodel.writeDirTree, dupes, "temp/dir-tree-dupes"))
+assert(not pcall(remodel.writeDirTree, root, "temp/dir-tree-bad", { modelFormat = "rbxl" }))
@@ -0,0 +1,32 @@
+local first = Instance.new("Folder")
+first.Name = "First"
+
+local second = Instance.new("StringValue")
+second.Name = "Second"
+second.Value = "Hello"
+
+local child = Instance.new("Folder")
+child.Name = "Child"
+child.Parent = first
+
+for _, path in ipairs({ "temp/multiple-roots.rbxm", "temp/multiple-roots.rbxmx" }) do
+	remodel.writeModelFile(path, { first, second })
+
+	local roots = remodel.readModelFile(path)
+	assert(#roots == 2, path)
+	assert(roots[1].Name == "First", path)
+	assert(roots[1].Child ~= nil, path)
+	assert(roots[2].Name == "Second", path)
+	assert(roots[2].Value == "Hello", path)
+
+	-- Writing what we read should keep the same shape.
+	remodel.writeModelFile(path, roots)
+	assert(#remodel.readModelFile(path) == 2, path)
+end
+
+assert(#remodel.decodeModel(remodel.encodeModel({ first, second }, "rbxm")) == 2)
+
+assert(not pcall(remodel.writeModelFile, "temp/multiple-roots.rbxm", {}))
+assert(not pcall(remodel.writeModelFile, "temp/multiple-roots.rbxm", { first, child }))
+assert(not pcall(remodel.writeModelFile, "temp/multiple-roots.rbxm", { first, Instance.new("DataModel") }))
+assert(not pcall(remodel.writeModelFile, "temp/multiple-roots.rbxm", { first, "Second" }))
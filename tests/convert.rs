@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn remodel() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_remodel"))
+}
+
+fn header(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let contents = fs::read(path)?;
+    Ok(contents[..8].to_vec())
+}
+
+#[test]
+fn test_convert_file() -> anyhow::Result<()> {
+    let output = Path::new("temp/convert/place.rbxl");
+
+    let status = remodel()
+        .args(["convert", "test-models/place-with-models.rbxlx"])
+        .arg(output)
+        .status()?;
+    assert!(status.success(), "Converting a place failed");
+    assert_eq!(header(output)?, b"<roblox!");
+
+    // Places can't become models.
+    let status = remodel()
+        .args([
+            "convert",
+            "test-models/place-with-models.rbxlx",
+            "temp/convert/place.rbxm",
+        ])
+        .status()?;
+    assert!(!status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_convert_dir() -> anyhow::Result<()> {
+    let input = Path::new("temp/convert-input");
+    let output = Path::new("temp/convert-output");
+
+    fs::create_dir_all(input.join("nested"))?;
+    fs::copy("test-models/binary.rbxm", input.join("model.rbxm"))?;
+    fs::copy(
+        "test-models/place-with-models-binary.rbxl",
+        input.join("nested/place.rbxl"),
+    )?;
+    fs::write(input.join("notes.txt"), "Not a model")?;
+
+    let status = remodel()
+        .arg("convert")
+        .arg(input)
+        .arg(output)
+        .args(["--format", "xml"])
+        .status()?;
+    assert!(status.success(), "Converting a directory failed");
+
+    assert_eq!(header(&output.join("model.rbxmx"))?, b"<roblox ");
+    assert_eq!(header(&output.join("nested/place.rbxlx"))?, b"<roblox ");
+    assert!(!output.join("notes.txt").exists());
+
+    Ok(())
+}
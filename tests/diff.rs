@@ -0,0 +1,58 @@
+use std::process::Command;
+
+fn diff(args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_remodel"))
+        .arg("diff")
+        .args(args)
+        .output()?;
+
+    assert!(output.status.success(), "remodel diff {:?} failed", args);
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[test]
+fn test_diff() -> anyhow::Result<()> {
+    assert_eq!(
+        diff(&[
+            "test-models/folder-and-value.rbxmx",
+            "test-models/folder-and-value-changed.rbxmx",
+        ])?,
+        "+ Root.Bool (BoolValue)\n\
+         - Root.Number (NumberValue)\n\
+         ~ Root.String (StringValue)\n    \
+         Value: \"Hello\" -> \"Goodbye\"\n\
+         1 added, 1 removed, 0 moved, 1 changed\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_json() -> anyhow::Result<()> {
+    let json: serde_json::Value = serde_json::from_str(&diff(&[
+        "test-models/folder-and-value.rbxmx",
+        "test-models/folder-and-value-changed.rbxmx",
+        "--json",
+    ])?)?;
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "added": [{ "path": "Root.Bool", "className": "BoolValue" }],
+            "removed": [{ "path": "Root.Number", "className": "NumberValue" }],
+            "moved": [],
+            "changed": [{
+                "path": "Root.String",
+                "className": "StringValue",
+                "properties": {
+                    "Value": {
+                        "old": { "type": "String", "value": "Hello" },
+                        "new": { "type": "String", "value": "Goodbye" },
+                    },
+                },
+            }],
+        })
+    );
+
+    Ok(())
+}
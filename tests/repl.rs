@@ -0,0 +1,85 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_repl() -> anyhow::Result<()> {
+    // Keeps the history of this test out of the real home directory.
+    let home = Path::new("temp/repl-home");
+    fs::create_dir_all(home)?;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_remodel"))
+        .arg("repl")
+        .env("HOME", home)
+        .env("USERPROFILE", home)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    child.stdin.take().unwrap().write_all(
+        b"value = 1 +\n\
+          2\n\
+          value\n\
+          folder = Instance.new(\"Folder\")\n\
+          folder.Name = \"Root\"\n\
+          local child = Instance.new(\"Model\"); child.Parent = folder\n\
+          { folder, folder.Model, name = \"x\", nested = {} }\n",
+    )?;
+
+    let output = child.wait_with_output()?;
+    assert!(output.status.success(), "REPL failed with an error");
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let expected = "3\n\
+                    {\n  \
+                    Root (Folder),\n  \
+                    Root.Model (Model),\n  \
+                    name = \"x\",\n  \
+                    nested = {},\n\
+                    }\n";
+
+    assert!(
+        stdout.ends_with(expected),
+        "Output from the REPL did not match expected output.\nExpected output:\n{}\nActual output:\n{}",
+        expected,
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_repl_keeps_running_after_print_errors() -> anyhow::Result<()> {
+    let home = Path::new("temp/repl-home");
+    fs::create_dir_all(home)?;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_remodel"))
+        .arg("repl")
+        .env("HOME", home)
+        .env("USERPROFILE", home)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Printing a value goes through `tostring`, so this can't print `1`.
+    child.stdin.take().unwrap().write_all(
+        b"tostring = nil\n\
+          1\n\
+          \"still running\"\n",
+    )?;
+
+    let output = child.wait_with_output()?;
+    assert!(output.status.success(), "REPL failed with an error");
+    assert!(!output.stderr.is_empty(), "REPL did not report the error");
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(
+        stdout.ends_with("\"still running\"\n"),
+        "REPL stopped after an error while printing.\nActual output:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
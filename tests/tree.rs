@@ -0,0 +1,90 @@
+use std::process::Command;
+
+fn tree(args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_remodel"))
+        .arg("tree")
+        .args(args)
+        .output()?;
+
+    assert!(output.status.success(), "remodel tree {:?} failed", args);
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[test]
+fn test_tree() -> anyhow::Result<()> {
+    assert_eq!(
+        tree(&["test-models/folder-and-value.rbxmx", "-p", "Value"])?,
+        "Root (Folder)\n  \
+         String (StringValue) Value=\"Hello\"\n  \
+         Number (NumberValue) Value=42.0\n"
+    );
+
+    assert_eq!(
+        tree(&["test-models/folder-and-value.rbxmx", "--depth", "0"])?,
+        "Root (Folder)\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tree_filters() -> anyhow::Result<()> {
+    let output = tree(&[
+        "test-models/place-with-models.rbxlx",
+        "--path",
+        "ReplicatedStorage.Models",
+        "--class",
+        "BasePart",
+        "--depth",
+        "2",
+    ])?;
+
+    assert_eq!(
+        output,
+        "Models (Folder)\n  \
+         Car (Model)\n    \
+         Part (Part)\n  \
+         CoolHat (Model)\n    \
+         Handle (Part)\n  \
+         Monkey (Model)\n    \
+         Handle (Part)\n"
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&tree(&[
+        "test-models/place-with-models.rbxlx",
+        "--path",
+        "Workspace.Baseplate",
+        "--json",
+        "-p",
+        "Anchored",
+    ])?)?;
+
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "name": "Baseplate",
+            "className": "Part",
+            "properties": { "Anchored": true },
+            "children": [],
+        }])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tree_unknown_class() -> anyhow::Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_remodel"))
+        .args([
+            "tree",
+            "test-models/folder-and-value.rbxmx",
+            "--class",
+            "Nonsense",
+        ])
+        .output()?;
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)?.contains("'Nonsense' is not a valid class name"));
+
+    Ok(())
+}